@@ -0,0 +1,161 @@
+use std::{fmt, path::PathBuf, process::ExitCode};
+
+use anyhow::{Context, Result};
+use arm_attr::{
+    error::ElfError,
+    merge::{check_compatible, ConflictKind, Severity},
+    tag::Tag,
+    Attributes, BuildAttrs, ParseOptions,
+};
+use clap::Args;
+use serde::{Serialize, Serializer};
+
+use crate::{input::read_inputs, Format};
+
+#[derive(Args, Debug)]
+pub struct CheckArgs {
+    /// Input ELF files and archives
+    #[arg(required = true)]
+    inputs: Vec<PathBuf>,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Fail on malformed .ARM.attributes sections, like a missing null terminator or overlapping scopes, instead
+    /// of printing warnings
+    #[arg(long)]
+    strict: bool,
+}
+
+#[derive(Serialize)]
+struct CheckReport<'a> {
+    passed: bool,
+    /// Inputs with public build attributes, which were checked
+    inputs: Vec<&'a str>,
+    conflicts: Vec<ConflictReport<'a>>,
+}
+
+#[derive(Serialize)]
+struct ConflictReport<'a> {
+    #[serde(serialize_with = "serialize_display")]
+    severity: Severity,
+    #[serde(serialize_with = "serialize_display")]
+    kind: ConflictKind,
+    a: TagReport<'a>,
+    b: TagReport<'a>,
+}
+
+#[derive(Serialize)]
+struct TagReport<'a> {
+    input: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tag: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<String>,
+}
+
+impl<'a> TagReport<'a> {
+    fn new(input: &'a str, tag: Option<Tag>) -> Self {
+        Self {
+            input,
+            tag: tag.as_ref().map(Tag::raw_tag),
+            name: tag.as_ref().and_then(Tag::name),
+            value: tag.as_ref().map(Tag::to_string),
+        }
+    }
+}
+
+impl<'a> fmt::Display for TagReport<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.tag, self.name, &self.value) {
+            (_, Some(name), Some(value)) => write!(f, "{}: {name} = {value}", self.input),
+            (Some(tag), None, Some(value)) => write!(f, "{}: Tag {tag} = {value}", self.input),
+            _ => write!(f, "{}: (default)", self.input),
+        }
+    }
+}
+
+pub fn run(args: CheckArgs) -> Result<ExitCode> {
+    let inputs = read_inputs(&args.inputs)?;
+    let mut build_attrs = vec![];
+    for input in &inputs {
+        match BuildAttrs::from_elf(&input.data) {
+            Ok(attrs) => build_attrs.push((input.name.as_str(), attrs)),
+            Err(ElfError::NoAttributesSection) => {}
+            Err(err) => return Err(err).with_context(|| format!("failed to read {}", input.name)),
+        }
+    }
+    let mut checked = vec![];
+    for (name, build_attrs) in &build_attrs {
+        let options = ParseOptions { strict: args.strict };
+        let attributes = public_attributes(name, build_attrs, options).with_context(|| format!("failed to read {name}"))?;
+        if let Some(attributes) = attributes {
+            checked.push((*name, attributes));
+        }
+    }
+
+    let mut conflicts = vec![];
+    for (i, (a_name, a)) in checked.iter().enumerate() {
+        for (b_name, b) in &checked[i + 1..] {
+            for incompatibility in check_compatible(a, b) {
+                conflicts.push(ConflictReport {
+                    severity: incompatibility.severity,
+                    kind: incompatibility.kind,
+                    a: TagReport::new(a_name, incompatibility.a),
+                    b: TagReport::new(b_name, incompatibility.b),
+                });
+            }
+        }
+    }
+    let passed = conflicts.iter().all(|conflict| conflict.severity < Severity::Error);
+
+    let report = CheckReport {
+        passed,
+        inputs: checked.iter().map(|(name, _)| *name).collect(),
+        conflicts,
+    };
+    match args.format {
+        Format::Text => {
+            for conflict in &report.conflicts {
+                println!("{}: {}", conflict.severity, conflict.kind);
+                println!("    {}", conflict.a);
+                println!("    {}", conflict.b);
+            }
+            let errors = report.conflicts.iter().filter(|c| c.severity == Severity::Error).count();
+            let warnings = report.conflicts.len() - errors;
+            println!("Checked {} inputs: {errors} errors, {warnings} warnings", report.inputs.len());
+        }
+        Format::Json => println!("{}", serde_json::to_string_pretty(&report)?),
+        Format::Yaml => print!("{}", serde_yaml::to_string(&report)?),
+    }
+
+    Ok(if passed { ExitCode::SUCCESS } else { ExitCode::FAILURE })
+}
+
+/// Returns the file-scope attributes of the "aeabi" subsection, if there is one. Warnings about malformed subsections are
+/// printed to stderr.
+fn public_attributes<'a>(name: &str, build_attrs: &'a BuildAttrs, options: ParseOptions) -> Result<Option<Attributes<'a>>> {
+    let mut subsections = build_attrs.subsections_with_options(options);
+    let mut attributes = None;
+    for subsection in subsections.by_ref() {
+        let subsection = subsection?;
+        if subsection.is_aeabi() && attributes.is_none() {
+            let (file, warnings) = subsection.into_public_attributes_with_warnings()?;
+            for warning in warnings {
+                eprintln!("warning: {name}: {warning}");
+            }
+            attributes = Some(file.attributes);
+        }
+    }
+    for warning in subsections.warnings() {
+        eprintln!("warning: {name}: {warning}");
+    }
+    Ok(attributes)
+}
+
+fn serialize_display<T: fmt::Display, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
@@ -0,0 +1,348 @@
+use std::{
+    collections::BTreeMap,
+    fmt,
+    io::{self, Write},
+    path::PathBuf,
+    process::ExitCode,
+};
+
+use anstyle::{AnsiColor, Style};
+use anyhow::Result;
+use arm_attr::{error::ElfError, BuildAttrs};
+use clap::Args;
+use serde::Serialize;
+
+use crate::{
+    read_file,
+    report::{AttributeReport, Report, ScopeKind, ScopeReport, Value},
+    Format,
+};
+
+#[derive(Args, Debug)]
+pub struct DiffArgs {
+    /// Old ELF file
+    a: PathBuf,
+
+    /// New ELF file
+    b: PathBuf,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+#[derive(Serialize)]
+struct Diff {
+    subsections: Vec<SubsectionDiff>,
+}
+
+#[derive(Serialize)]
+struct SubsectionDiff {
+    vendor: String,
+    status: Status,
+    scopes: Vec<ScopeDiff>,
+}
+
+#[derive(Serialize)]
+struct ScopeDiff {
+    scope: ScopeKind,
+    /// Section indices of the enclosing section scope, for symbol scopes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    section: Option<Vec<u32>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    section_names: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    indices: Vec<u32>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    names: Vec<String>,
+    status: Status,
+    changes: Vec<TagDiff>,
+}
+
+#[derive(Serialize)]
+struct TagDiff {
+    status: Status,
+    tag: u32,
+    name: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    old: Option<ValueDiff>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    new: Option<ValueDiff>,
+}
+
+#[derive(Serialize)]
+struct ValueDiff {
+    value: Value,
+    meaning: String,
+}
+
+#[derive(Serialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "lowercase")]
+enum Status {
+    Added,
+    Removed,
+    Changed,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Added => write!(f, "added"),
+            Self::Removed => write!(f, "removed"),
+            Self::Changed => write!(f, "changed"),
+        }
+    }
+}
+
+/// Identifies a scope by its own indices and those of its enclosing section scope, if any.
+type ScopeKey = (Option<Vec<u32>>, Option<Vec<u32>>);
+
+struct Scope {
+    kind: ScopeKind,
+    names: Vec<String>,
+    section_names: Vec<String>,
+    attributes: BTreeMap<u32, AttributeReport>,
+}
+
+pub fn run(args: DiffArgs) -> Result<ExitCode> {
+    // Exit code 1 means that the files differ, so errors get their own
+    match compare(&args) {
+        Ok(true) => Ok(ExitCode::FAILURE),
+        Ok(false) => Ok(ExitCode::SUCCESS),
+        Err(err) => {
+            eprintln!("Error: {err:?}");
+            Ok(ExitCode::from(2))
+        }
+    }
+}
+
+/// Prints the differences between the two files, and returns whether there are any.
+fn compare(args: &DiffArgs) -> Result<bool> {
+    let a = subsections(&read_file(&args.a)?)?;
+    let b = subsections(&read_file(&args.b)?)?;
+    let diff = diff(a, b);
+
+    match args.format {
+        Format::Text => print_diff(&diff)?,
+        Format::Json => println!("{}", serde_json::to_string_pretty(&diff)?),
+        Format::Yaml => print!("{}", serde_yaml::to_string(&diff)?),
+    }
+    Ok(!diff.subsections.is_empty())
+}
+
+/// Returns the attributes of each subsection by vendor name. A file without build attributes has no subsections, so that
+/// all attributes of the other file show up as added or removed.
+fn subsections(data: &[u8]) -> Result<BTreeMap<String, BTreeMap<ScopeKey, Scope>>> {
+    let build_attrs = match BuildAttrs::from_elf(data) {
+        Ok(build_attrs) => build_attrs,
+        Err(ElfError::NoAttributesSection) => return Ok(BTreeMap::new()),
+        Err(err) => return Err(err.into()),
+    };
+    let object = object::File::parse(data)?;
+    let mut report = Report::new();
+    for subsection in build_attrs.subsections() {
+        report.add_subsection(&object, subsection?, false)?;
+    }
+
+    let mut subsections = BTreeMap::<_, BTreeMap<_, _>>::new();
+    for subsection in report.subsections {
+        let scopes = subsections.entry(subsection.vendor).or_default();
+        let (mut section, mut section_names) = (None, vec![]);
+        for scope in subsection.scopes {
+            let key = match scope.scope {
+                ScopeKind::File => (None, None),
+                ScopeKind::Section => {
+                    section = Some(scope.indices.clone());
+                    section_names.clone_from(&scope.names);
+                    (Some(scope.indices.clone()), None)
+                }
+                ScopeKind::Symbol => (section.clone(), Some(scope.indices.clone())),
+            };
+            let section_names = match scope.scope {
+                ScopeKind::Symbol => section_names.clone(),
+                _ => vec![],
+            };
+            let ScopeReport {
+                scope: kind,
+                names,
+                attributes,
+                ..
+            } = scope;
+            let entry = scopes.entry(key).or_insert_with(|| Scope {
+                kind,
+                names,
+                section_names,
+                attributes: BTreeMap::new(),
+            });
+            entry.attributes.extend(attributes.into_iter().map(|attr| (attr.tag, attr)));
+        }
+    }
+    Ok(subsections)
+}
+
+fn diff(mut a: BTreeMap<String, BTreeMap<ScopeKey, Scope>>, mut b: BTreeMap<String, BTreeMap<ScopeKey, Scope>>) -> Diff {
+    let mut vendors = a.keys().chain(b.keys()).cloned().collect::<Vec<_>>();
+    vendors.sort();
+    vendors.dedup();
+
+    let mut subsections = vec![];
+    for vendor in vendors {
+        let (status, a_scopes, b_scopes) = match (a.remove(&vendor), b.remove(&vendor)) {
+            (Some(a), Some(b)) => (Status::Changed, a, b),
+            (Some(a), None) => (Status::Removed, a, BTreeMap::new()),
+            (None, Some(b)) => (Status::Added, BTreeMap::new(), b),
+            (None, None) => unreachable!(),
+        };
+        let scopes = diff_scopes(a_scopes, b_scopes);
+        if !scopes.is_empty() {
+            subsections.push(SubsectionDiff { vendor, status, scopes });
+        }
+    }
+    Diff { subsections }
+}
+
+fn diff_scopes(mut a: BTreeMap<ScopeKey, Scope>, mut b: BTreeMap<ScopeKey, Scope>) -> Vec<ScopeDiff> {
+    let mut keys = a.keys().chain(b.keys()).cloned().collect::<Vec<_>>();
+    keys.sort();
+    keys.dedup();
+
+    let mut scopes = vec![];
+    for key in keys {
+        let (status, old, new) = match (a.remove(&key), b.remove(&key)) {
+            (Some(a), Some(b)) => (Status::Changed, Some(a), Some(b)),
+            (Some(a), None) => (Status::Removed, Some(a), None),
+            (None, Some(b)) => (Status::Added, None, Some(b)),
+            (None, None) => unreachable!(),
+        };
+        let Some(Scope {
+            kind,
+            names,
+            section_names,
+            attributes: _,
+        }) = new.as_ref().or(old.as_ref())
+        else {
+            unreachable!()
+        };
+        let (kind, names, section_names) = (*kind, names.clone(), section_names.clone());
+        let changes = diff_attributes(
+            old.map(|scope| scope.attributes).unwrap_or_default(),
+            new.map(|scope| scope.attributes).unwrap_or_default(),
+        );
+        if changes.is_empty() && status == Status::Changed {
+            continue;
+        }
+        let (section, indices) = match key {
+            (section, Some(symbols)) => (section, symbols),
+            (Some(sections), None) => (None, sections),
+            (None, None) => (None, vec![]),
+        };
+        scopes.push(ScopeDiff {
+            scope: kind,
+            section,
+            section_names,
+            indices,
+            names,
+            status,
+            changes,
+        });
+    }
+    scopes
+}
+
+fn diff_attributes(mut a: BTreeMap<u32, AttributeReport>, mut b: BTreeMap<u32, AttributeReport>) -> Vec<TagDiff> {
+    let mut tags = a.keys().chain(b.keys()).copied().collect::<Vec<_>>();
+    tags.sort();
+    tags.dedup();
+
+    let mut changes = vec![];
+    for tag in tags {
+        let (old, new) = (a.remove(&tag), b.remove(&tag));
+        let status = match (&old, &new) {
+            (Some(old), Some(new)) if old.value == new.value => continue,
+            (Some(_), Some(_)) => Status::Changed,
+            (Some(_), None) => Status::Removed,
+            (None, Some(_)) => Status::Added,
+            (None, None) => unreachable!(),
+        };
+        let name = old.as_ref().or(new.as_ref()).and_then(|attr| attr.name);
+        let value = |attr: AttributeReport| ValueDiff {
+            value: attr.value,
+            meaning: attr.meaning,
+        };
+        changes.push(TagDiff {
+            status,
+            tag,
+            name,
+            old: old.map(value),
+            new: new.map(value),
+        });
+    }
+    changes
+}
+
+fn print_diff(diff: &Diff) -> io::Result<()> {
+    let mut out = anstream::stdout();
+    let style = |status| match status {
+        Status::Added => Style::new().fg_color(Some(AnsiColor::Green.into())),
+        Status::Removed => Style::new().fg_color(Some(AnsiColor::Red.into())),
+        Status::Changed => Style::new().fg_color(Some(AnsiColor::Yellow.into())),
+    };
+    let marker = |status| match status {
+        Status::Added => '+',
+        Status::Removed => '-',
+        Status::Changed => '~',
+    };
+
+    for subsection in &diff.subsections {
+        match subsection.status {
+            Status::Changed => writeln!(out, "Vendor: {}", subsection.vendor)?,
+            status => {
+                let style = style(status);
+                writeln!(out, "{style}Vendor: {} ({status}){style:#}", subsection.vendor)?
+            }
+        }
+        for scope in &subsection.scopes {
+            let title = match scope.scope {
+                ScopeKind::File => "File scope".to_string(),
+                ScopeKind::Section => format!("Section scope {}", names(&scope.names, &scope.indices)),
+                ScopeKind::Symbol => match &scope.section {
+                    Some(section) => format!(
+                        "Symbol scope {} of section {}",
+                        names(&scope.names, &scope.indices),
+                        names(&scope.section_names, section)
+                    ),
+                    None => format!("Symbol scope {}", names(&scope.names, &scope.indices)),
+                },
+            };
+            match scope.status {
+                Status::Changed => writeln!(out, "    {title}:")?,
+                status => {
+                    let style = style(status);
+                    writeln!(out, "    {style}{title} ({status}):{style:#}")?
+                }
+            }
+            for change in &scope.changes {
+                let style = style(change.status);
+                let name = match change.name {
+                    Some(name) => name.to_string(),
+                    None => format!("Tag {}", change.tag),
+                };
+                let value = match (&change.old, &change.new) {
+                    (Some(old), Some(new)) => format!("{} -> {}", old.meaning, new.meaning),
+                    (Some(value), None) | (None, Some(value)) => value.meaning.clone(),
+                    (None, None) => unreachable!(),
+                };
+                writeln!(out, "        {style}{} {name} : {value}{style:#}", marker(change.status))?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn names(names: &[String], indices: &[u32]) -> String {
+    if names.is_empty() {
+        format!("{indices:?}")
+    } else {
+        names.join(", ")
+    }
+}
@@ -0,0 +1,325 @@
+use std::{
+    io::{self, StdoutLock, Write},
+    path::PathBuf,
+    process::ExitCode,
+};
+
+use anyhow::{bail, Context, Result};
+use arm_attr::{
+    archive::Archive,
+    error::ElfError,
+    vendor::{default_parser, VendorTag},
+    AttributeDisplayOptions, BuildAttrs, Subsection,
+};
+use clap::Args;
+use serde::Serialize;
+
+use crate::{
+    input::{find_inputs, read_archive, read_inputs},
+    parse_tag_name,
+    raw::print_raw,
+    read_file,
+    readelf::print_build_attrs,
+    report::{section_name, symbol_name, Report, ScopeKind},
+    summary::{print_table, SummaryRow},
+    Format,
+};
+
+#[derive(Args, Debug)]
+pub struct DumpArgs {
+    /// Input ELF file, or archive whose members are listed one by one
+    #[arg(short = 'f', long = "file", value_name = "FILE")]
+    files: Vec<PathBuf>,
+
+    /// Input ELF files, archives or, with -r, directories. Several inputs are listed in a summary table
+    #[arg(value_name = "PATH", required_unless_present = "files")]
+    paths: Vec<PathBuf>,
+
+    /// Search directories recursively for ARM ELF files and archives, and list them in a summary table
+    #[arg(short = 'r', long)]
+    recursive: bool,
+
+    /// Show default values of attributes not specified in the input
+    #[arg(short = 'd', long)]
+    show_defaults: bool,
+
+    /// Show target attributes. Enabled by default if -p and -m are not given
+    #[arg(short = 't', long)]
+    show_target: bool,
+
+    /// Show procedure-call attributes. Enabled by default if -t and -m are not given
+    #[arg(short = 'p', long)]
+    show_pcs: bool,
+
+    /// Show miscellaneous attributes. Enabled by default if -t and -p are not given
+    #[arg(short = 'm', long)]
+    show_misc: bool,
+
+    /// Only show these public attributes, given as a comma-separated list of tag names or numbers. Only applies to a
+    /// single file or archive
+    #[arg(long, value_name = "TAGS", value_delimiter = ',', conflicts_with_all = ["show_target", "show_pcs", "show_misc"])]
+    tags: Vec<String>,
+
+    /// Output format. The -t, -p and -m filters only apply to text output
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Print attributes in the order they are stored, exactly like `readelf -A` of GNU binutils 2.40
+    #[arg(long, conflicts_with_all = ["format", "tags", "show_defaults", "show_target", "show_pcs", "show_misc"])]
+    readelf: bool,
+
+    /// Print every subsection header and tag with its offset, encoded bytes and decoded value, and mark where parsing
+    /// failed in malformed sections
+    #[arg(long, conflicts_with_all = ["format", "readelf", "tags", "show_defaults", "show_target", "show_pcs", "show_misc"])]
+    raw: bool,
+}
+
+/// Build attributes of an archive member.
+#[derive(Serialize)]
+struct MemberReport {
+    member: String,
+    #[serde(flatten)]
+    report: Report,
+}
+
+pub fn run(args: DumpArgs) -> Result<ExitCode> {
+    let paths = [args.files.as_slice(), args.paths.as_slice()].concat();
+    if args.readelf {
+        return run_printer(&args, &paths, print_build_attrs);
+    }
+    if args.raw {
+        return run_printer(&args, &paths, print_raw);
+    }
+    if args.recursive || paths.len() > 1 {
+        if !args.tags.is_empty() {
+            bail!("--tags only applies to a single file or archive");
+        }
+        return run_summary(&args, &paths);
+    }
+    let tags = args
+        .tags
+        .iter()
+        .map(|name| parse_tag_name(name))
+        .collect::<Result<Vec<_>>>()?;
+    let path = &paths[0];
+    if path.is_dir() {
+        bail!("{} is a directory, use -r to search it", path.display());
+    }
+    let data = read_file(path)?;
+    if Archive::parse(&data).is_err() {
+        let build_attrs = BuildAttrs::from_elf(&data)?;
+        let object = object::File::parse(&*data)?;
+        match args.format {
+            Format::Text if !tags.is_empty() => print_report(&report(&args, &tags, &object, &build_attrs)?),
+            Format::Text => print_object(&args, &object, &build_attrs)?,
+            Format::Json => println!(
+                "{}",
+                serde_json::to_string_pretty(&report(&args, &tags, &object, &build_attrs)?)?
+            ),
+            Format::Yaml => print!("{}", serde_yaml::to_string(&report(&args, &tags, &object, &build_attrs)?)?),
+        }
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let mut reports = vec![];
+    for member in read_archive(path, &data)? {
+        let build_attrs = match BuildAttrs::from_elf(&member.data) {
+            Ok(build_attrs) => build_attrs,
+            Err(ElfError::NoAttributesSection) => continue,
+            Err(err) => return Err(err).with_context(|| format!("failed to read {}", member.name)),
+        };
+        let object = object::File::parse(&*member.data)?;
+        if args.format == Format::Text {
+            println!("Member: {}", member.name);
+            if tags.is_empty() {
+                print_object(&args, &object, &build_attrs)?;
+            } else {
+                print_report(&report(&args, &tags, &object, &build_attrs)?);
+            }
+        } else {
+            reports.push(MemberReport {
+                report: report(&args, &tags, &object, &build_attrs)?,
+                member: member.name,
+            });
+        }
+    }
+    match args.format {
+        Format::Text => {}
+        Format::Json => println!("{}", serde_json::to_string_pretty(&reports)?),
+        Format::Yaml => print!("{}", serde_yaml::to_string(&reports)?),
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn run_summary(args: &DumpArgs, paths: &[PathBuf]) -> Result<ExitCode> {
+    let inputs = if args.recursive {
+        find_inputs(paths)?
+    } else {
+        read_inputs(paths)?
+    };
+    let mut rows = vec![];
+    for input in inputs {
+        let build_attrs = match BuildAttrs::from_elf(&input.data) {
+            Ok(build_attrs) => Some(build_attrs),
+            Err(ElfError::NoAttributesSection) => None,
+            Err(err) => return Err(err).with_context(|| format!("failed to read {}", input.name)),
+        };
+        let name = input.name.clone();
+        rows.push(SummaryRow::new(input.name, build_attrs.as_ref()).with_context(|| format!("failed to read {name}"))?);
+    }
+    match args.format {
+        Format::Text => print_table(&rows),
+        Format::Json => println!("{}", serde_json::to_string_pretty(&rows)?),
+        Format::Yaml => print!("{}", serde_yaml::to_string(&rows)?),
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Prints the build attributes of each input with `print`, like readelf does.
+fn run_printer(
+    args: &DumpArgs,
+    paths: &[PathBuf],
+    print: fn(&mut StdoutLock<'static>, &BuildAttrs) -> Result<()>,
+) -> Result<ExitCode> {
+    let inputs = if args.recursive {
+        find_inputs(paths)?
+    } else {
+        read_inputs(paths)?
+    };
+    // Like readelf, name the inputs unless a single ELF file was given
+    let single_file = paths.len() == 1 && inputs.len() == 1 && inputs[0].name == paths[0].display().to_string();
+    let mut out = io::stdout().lock();
+    for input in &inputs {
+        if !single_file {
+            writeln!(out, "\nFile: {}", input.name)?;
+        }
+        match BuildAttrs::from_elf(&input.data) {
+            Ok(build_attrs) => print(&mut out, &build_attrs).with_context(|| format!("failed to read {}", input.name))?,
+            Err(ElfError::NoAttributesSection) => {}
+            Err(err) => return Err(err).with_context(|| format!("failed to read {}", input.name)),
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Builds the report of an object. If `tags` is not empty, only those public attributes are kept.
+fn report(args: &DumpArgs, tags: &[u32], object: &object::File, build_attrs: &BuildAttrs) -> Result<Report> {
+    let mut report = Report::new();
+    for subsection in build_attrs.subsections() {
+        let subsection = subsection?;
+        if !tags.is_empty() && !subsection.is_aeabi() {
+            continue;
+        }
+        report.add_subsection(object, subsection, args.show_defaults)?;
+    }
+    if !tags.is_empty() {
+        let scopes = report.subsections.iter_mut().flat_map(|subsection| &mut subsection.scopes);
+        scopes.for_each(|scope| scope.attributes.retain(|attribute| tags.contains(&attribute.tag)));
+    }
+    Ok(report)
+}
+
+/// Prints a report as text, listing attributes by tag name.
+fn print_report(report: &Report) {
+    for subsection in &report.subsections {
+        println!("Vendor: {}", subsection.vendor);
+        for scope in &subsection.scopes {
+            let origin = |parent| match scope.inherited {
+                true => format!("inherited from {parent} scope"),
+                false => "explicit".to_string(),
+            };
+            let indent = match scope.scope {
+                ScopeKind::File => {
+                    println!("    File scope:");
+                    8
+                }
+                ScopeKind::Section => {
+                    println!("    Section scope {} ({}):", scope.names.join(", "), origin("file"));
+                    8
+                }
+                ScopeKind::Symbol => {
+                    println!("        Symbol scope {} ({}):", scope.names.join(", "), origin("section"));
+                    12
+                }
+            };
+            for attribute in &scope.attributes {
+                let name = match attribute.name {
+                    Some(name) => format!("{name} "),
+                    None => format!("Tag {} ", attribute.tag),
+                };
+                let default = if attribute.default { "[default] " } else { "" };
+                println!("{:indent$}{name:.<30} : {default}{}", "", attribute.meaning);
+            }
+        }
+    }
+}
+
+fn print_object(args: &DumpArgs, object: &object::File, build_attrs: &BuildAttrs) -> Result<()> {
+    let show_all = !args.show_target && !args.show_pcs && !args.show_misc;
+    let options = |indent| AttributeDisplayOptions {
+        indent,
+        show_defaults: args.show_defaults,
+        show_target: show_all || args.show_target,
+        show_pcs: show_all || args.show_pcs,
+        show_misc: show_all || args.show_misc,
+    };
+
+    for section in build_attrs.subsections() {
+        let section = section?;
+        println!("Vendor: {}", section.vendor_name());
+        if !section.is_aeabi() {
+            print_vendor_tags(section);
+            continue;
+        }
+        let attributes = section.into_public_attributes()?;
+        println!("    File scope:");
+        println!("{}", attributes.attributes.display(options(8)));
+        for (sections, section) in &attributes.sections {
+            let origin = if section.inherited {
+                "inherited from file scope"
+            } else {
+                "explicit"
+            };
+            println!("    Section scope {} ({origin}):", section_names(object, sections));
+            println!("{}", section.attributes.display(options(8)));
+            for (symbols, symbol) in &section.symbols {
+                let origin = if symbol.inherited {
+                    "inherited from section scope"
+                } else {
+                    "explicit"
+                };
+                println!("        Symbol scope {} ({origin}):", symbol_names(object, symbols));
+                println!("{}", symbol.attributes.display(options(12)));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn print_vendor_tags(subsection: Subsection) {
+    let parser = default_parser(subsection.vendor_name());
+    for (_, tag) in subsection.into_vendor_tag_iter(parser) {
+        match tag {
+            VendorTag::File { end_offset: _ } => println!("    File scope:"),
+            VendorTag::Section { end_offset: _, sections } => println!("    Section scope {sections:?}:"),
+            VendorTag::Symbol { end_offset: _, symbols } => println!("    Symbol scope {symbols:?}:"),
+            VendorTag::Attribute { tag, value } => {
+                let name = match parser.tag_name(tag) {
+                    Some(name) => format!("{name} "),
+                    None => format!("Tag {tag} "),
+                };
+                println!("        {name:.<22} : {value}");
+            }
+        }
+    }
+}
+
+fn section_names(object: &object::File, sections: &[u32]) -> String {
+    let names = sections.iter().map(|&index| section_name(object, index));
+    names.collect::<Vec<_>>().join(", ")
+}
+
+fn symbol_names(object: &object::File, symbols: &[u32]) -> String {
+    let names = symbols.iter().map(|&value| symbol_name(object, value));
+    names.collect::<Vec<_>>().join(", ")
+}
@@ -0,0 +1,110 @@
+use std::{path::PathBuf, process::ExitCode};
+
+use anyhow::{bail, Context, Result};
+use arm_attr::{error::ElfError, Attributes, BuildAttrs};
+use clap::Args;
+
+use crate::{
+    input::read_inputs,
+    parse_tag_name,
+    report::{attribute_reports, AttributeReport, Report, ScopeKind, Value},
+    set::{parse_scope, Scope},
+};
+
+#[derive(Args, Debug)]
+pub struct GetArgs {
+    /// Tag name like Tag_ABI_VFP_args or a deprecated name like Tag_VFP_arch, or a tag number
+    tag: String,
+
+    /// Input ELF files or archives
+    #[arg(required = true)]
+    paths: Vec<PathBuf>,
+
+    /// Print the encoded value, like 1 instead of "VFP variant"
+    #[arg(long)]
+    raw: bool,
+
+    /// Print nothing for inputs where the attribute is not set, instead of its default value
+    #[arg(long)]
+    explicit: bool,
+
+    /// Scope to get the attribute from: "file", "section:LIST" or "symbol:LIST", where LIST is a comma-separated list of
+    /// section or symbol indices or names
+    #[arg(long, default_value = "file")]
+    scope: String,
+}
+
+/// Exits with 1 if the attribute was printed for none of the inputs.
+pub fn run(args: GetArgs) -> Result<ExitCode> {
+    let tag = parse_tag_name(&args.tag)?;
+    let inputs = read_inputs(&args.paths)?;
+    // Name the inputs unless a single ELF file was given
+    let single_file = args.paths.len() == 1 && inputs.len() == 1 && inputs[0].name == args.paths[0].display().to_string();
+
+    let mut found = false;
+    for input in &inputs {
+        let context = || format!("failed to read {}", input.name);
+        let object = object::File::parse(&*input.data).with_context(context)?;
+        let scope = parse_scope(&object, &args.scope).with_context(context)?;
+        let mut report = Report::new();
+        match BuildAttrs::from_elf(&input.data) {
+            Ok(build_attrs) => {
+                for subsection in build_attrs.subsections() {
+                    let subsection = subsection.with_context(context)?;
+                    if subsection.is_aeabi() {
+                        report.add_subsection(&object, subsection, true).with_context(context)?;
+                    }
+                }
+            }
+            Err(ElfError::NoAttributesSection) => {}
+            Err(err) => return Err(err).with_context(context),
+        }
+
+        let attributes = scope_attributes(&report, &scope).with_context(context)?;
+        let Some(attribute) = attributes.into_iter().find(|attribute| attribute.tag == tag) else {
+            continue;
+        };
+        if args.explicit && attribute.default {
+            continue;
+        }
+        let value = if args.raw {
+            raw_value(&attribute.value)
+        } else {
+            attribute.meaning
+        };
+        if single_file {
+            println!("{value}");
+        } else {
+            println!("{}: {value}", input.name);
+        }
+        found = true;
+    }
+    Ok(if found { ExitCode::SUCCESS } else { ExitCode::from(1) })
+}
+
+/// Returns the attributes of the scope, including defaults. Without public attributes, the file scope has only defaults.
+fn scope_attributes(report: &Report, scope: &Scope) -> Result<Vec<AttributeReport>> {
+    let (kind, indices) = match scope {
+        Scope::File => (ScopeKind::File, &vec![]),
+        Scope::Section(sections) => (ScopeKind::Section, sections),
+        Scope::Symbol(symbols) => (ScopeKind::Symbol, symbols),
+    };
+    let mut scopes = report.subsections.iter().flat_map(|subsection| &subsection.scopes);
+    match scopes.find(|scope| scope.scope == kind && &scope.indices == indices) {
+        Some(scope) => Ok(scope.attributes.clone()),
+        None => match scope {
+            Scope::File => Ok(attribute_reports(&Attributes::default(), true)),
+            Scope::Section(sections) => bail!("no section scope for sections {sections:?}"),
+            Scope::Symbol(symbols) => bail!("no symbol scope for symbols {symbols:?}"),
+        },
+    }
+}
+
+fn raw_value(value: &Value) -> String {
+    match value {
+        Value::Number(number) => number.to_string(),
+        Value::String(string) => string.clone(),
+        Value::NumberString(number, string) => format!("{number}, {string}"),
+        Value::Flag(flag) => flag.to_string(),
+    }
+}
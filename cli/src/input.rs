@@ -0,0 +1,158 @@
+use std::{
+    fs,
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+use arm_attr::archive::Archive;
+use object::{Architecture, Object};
+
+use crate::read_file;
+
+const ELF_MAGIC: &[u8] = b"\x7fELF";
+/// Size of the longest magic number of the recognized files, the one of archives
+const MAGIC_SIZE: u64 = 8;
+
+/// An ELF file given on the command line, or a member of an archive given on the command line.
+pub struct Input {
+    pub name: String,
+    pub data: Vec<u8>,
+}
+
+/// Reads the given files, and expands archives into their members.
+pub fn read_inputs(paths: &[PathBuf]) -> Result<Vec<Input>> {
+    let mut inputs = vec![];
+    for path in paths {
+        if path.is_dir() {
+            bail!("{} is a directory, use -r to search it", path.display());
+        }
+        let data = read_file(path).with_context(|| format!("failed to read {}", path.display()))?;
+        if Archive::parse(&data).is_ok() {
+            inputs.extend(read_archive(path, &data)?);
+        } else {
+            inputs.push(Input {
+                name: path.display().to_string(),
+                data,
+            });
+        }
+    }
+    Ok(inputs)
+}
+
+/// Reads the members of an archive which are ELF files. Members of thin archives are read from their paths relative to the
+/// archive, and named like `lib.a[member.o]` instead of `lib.a(member.o)` as binutils does.
+pub fn read_archive(path: &Path, data: &[u8]) -> Result<Vec<Input>> {
+    read_archive_members(path, data, Err)
+}
+
+/// Like [`read_archive`], but passes the error of each member which fails to be read to `on_error`, which decides whether
+/// to skip the member or fail.
+fn read_archive_members(
+    path: &Path,
+    data: &[u8],
+    mut on_error: impl FnMut(anyhow::Error) -> Result<()>,
+) -> Result<Vec<Input>> {
+    let archive = Archive::parse(data)?;
+    let mut inputs = vec![];
+    for member in archive.members() {
+        let member = match member.with_context(|| format!("failed to read archive {}", path.display())) {
+            Ok(member) => member,
+            Err(err) => {
+                on_error(err)?;
+                continue;
+            }
+        };
+        let data = match member.data {
+            Some(data) => data.to_vec(),
+            None => {
+                let member_path = path.parent().unwrap_or(Path::new("")).join(member.name);
+                match read_file(&member_path).with_context(|| format!("failed to read {}", member_path.display())) {
+                    Ok(data) => data,
+                    Err(err) => {
+                        on_error(err)?;
+                        continue;
+                    }
+                }
+            }
+        };
+        if !data.starts_with(ELF_MAGIC) {
+            continue;
+        }
+        let name = if archive.is_thin() {
+            format!("{}[{}]", path.display(), member.name)
+        } else {
+            format!("{}({})", path.display(), member.name)
+        };
+        inputs.push(Input { name, data });
+    }
+    Ok(inputs)
+}
+
+/// Like [`read_inputs`], but also searches directories recursively for ARM ELF files and archives. Other files found in
+/// directories are skipped, as are symbolic links to avoid cycles. Files and archive members in directories which fail to be
+/// read are reported and skipped.
+pub fn find_inputs(paths: &[PathBuf]) -> Result<Vec<Input>> {
+    let mut inputs = vec![];
+    for path in paths {
+        if path.is_dir() {
+            find_in_dir(path, &mut inputs)?;
+        } else {
+            inputs.extend(read_inputs(std::slice::from_ref(path))?);
+        }
+    }
+    Ok(inputs)
+}
+
+fn find_in_dir(dir: &Path, inputs: &mut Vec<Input>) -> Result<()> {
+    let context = || format!("failed to read directory {}", dir.display());
+    let mut entries = fs::read_dir(dir)
+        .with_context(context)?
+        .collect::<Result<Vec<_>, _>>()
+        .with_context(context)?;
+    entries.sort_by_key(|entry| entry.file_name());
+    for entry in entries {
+        let path = entry.path();
+        let file_type = entry.file_type().with_context(context)?;
+        if file_type.is_dir() {
+            find_in_dir(&path, inputs)?;
+        } else if file_type.is_file() {
+            if let Err(err) = find_in_file(&path, inputs) {
+                warn(err)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Adds the file if it's an ARM ELF file, or its members which are if it's an archive. Only the start of other files is read.
+fn find_in_file(path: &Path, inputs: &mut Vec<Input>) -> Result<()> {
+    let context = || format!("failed to read {}", path.display());
+    let mut magic = vec![];
+    fs::File::open(path)
+        .and_then(|file| file.take(MAGIC_SIZE).read_to_end(&mut magic))
+        .with_context(context)?;
+    let found = if magic.starts_with(ELF_MAGIC) {
+        vec![Input {
+            name: path.display().to_string(),
+            data: read_file(path).with_context(context)?,
+        }]
+    } else if Archive::parse(&magic).is_ok() {
+        let data = read_file(path).with_context(context)?;
+        read_archive_members(path, &data, warn)?
+    } else {
+        return Ok(());
+    };
+    inputs.extend(found.into_iter().filter(|input| is_arm_elf(&input.data)));
+    Ok(())
+}
+
+/// Reports an error and continues.
+fn warn(err: anyhow::Error) -> Result<()> {
+    eprintln!("warning: {err:#}");
+    Ok(())
+}
+
+fn is_arm_elf(data: &[u8]) -> bool {
+    data.starts_with(ELF_MAGIC) && object::File::parse(data).is_ok_and(|file| file.architecture() == Architecture::Arm)
+}
@@ -0,0 +1,117 @@
+use std::{path::PathBuf, process::ExitCode};
+
+use anyhow::{Context, Result};
+use arm_attr::{error::ElfError, lint::lint_file, tag::Tag, BuildAttrs};
+use clap::Args;
+use serde::Serialize;
+
+use crate::{input::read_inputs, Format};
+
+#[derive(Args, Debug)]
+pub struct LintArgs {
+    /// Input ELF files and archives
+    #[arg(required = true)]
+    inputs: Vec<PathBuf>,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+#[derive(Serialize)]
+struct LintReport<'a> {
+    /// Inputs with public build attributes, which were linted
+    inputs: Vec<&'a str>,
+    diagnostics: Vec<DiagnosticReport<'a>>,
+}
+
+#[derive(Serialize)]
+struct DiagnosticReport<'a> {
+    input: &'a str,
+    code: &'static str,
+    message: String,
+    scope: String,
+    tags: Vec<TagReport>,
+}
+
+#[derive(Serialize)]
+struct TagReport {
+    tag: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<&'static str>,
+    value: String,
+}
+
+impl TagReport {
+    fn new(tag: &Tag) -> Self {
+        Self {
+            tag: tag.raw_tag(),
+            name: tag.name(),
+            value: tag.to_string(),
+        }
+    }
+}
+
+/// Exits with 1 if any diagnostic was found.
+pub fn run(args: LintArgs) -> Result<ExitCode> {
+    let inputs = read_inputs(&args.inputs)?;
+    let mut report = LintReport {
+        inputs: vec![],
+        diagnostics: vec![],
+    };
+    for input in &inputs {
+        let context = || format!("failed to read {}", input.name);
+        let build_attrs = match BuildAttrs::from_elf(&input.data) {
+            Ok(build_attrs) => build_attrs,
+            Err(ElfError::NoAttributesSection) => continue,
+            Err(err) => return Err(err).with_context(context),
+        };
+        for subsection in build_attrs.subsections() {
+            let subsection = subsection.with_context(context)?;
+            if !subsection.is_aeabi() {
+                continue;
+            }
+            let file = subsection.into_public_attributes().with_context(context)?;
+            report.inputs.push(&input.name);
+            for (scope, diagnostic) in lint_file(&file) {
+                report.diagnostics.push(DiagnosticReport {
+                    input: &input.name,
+                    code: diagnostic.lint.code(),
+                    message: diagnostic.lint.to_string(),
+                    scope: scope.to_string(),
+                    tags: diagnostic.tags.iter().map(TagReport::new).collect(),
+                });
+            }
+        }
+    }
+
+    match args.format {
+        Format::Text => {
+            for diagnostic in &report.diagnostics {
+                println!(
+                    "{}: {} {}, in {}",
+                    diagnostic.input, diagnostic.code, diagnostic.message, diagnostic.scope
+                );
+                for tag in &diagnostic.tags {
+                    match tag.name {
+                        Some(name) => println!("    {name} = {}", tag.value),
+                        None => println!("    Tag {} = {}", tag.tag, tag.value),
+                    }
+                }
+            }
+            println!(
+                "Linted {} inputs: {} diagnostics",
+                report.inputs.len(),
+                report.diagnostics.len()
+            );
+        }
+        Format::Json => println!("{}", serde_json::to_string_pretty(&report)?),
+        Format::Yaml => print!("{}", serde_yaml::to_string(&report)?),
+    }
+
+    Ok(if report.diagnostics.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
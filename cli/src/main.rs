@@ -1,77 +1,85 @@
-use std::{fs, io::Read, path::PathBuf};
+mod check;
+mod diff;
+mod dump;
+mod get;
+mod input;
+mod lint;
+mod raw;
+mod readelf;
+mod report;
+mod set;
+mod summary;
+
+use std::{fs, io::Read, path::Path, process::ExitCode};
 
 use anyhow::{anyhow, Result};
-use arm_attr::{read::Endian, AttributeDisplayOptions, BuildAttrs};
-use clap::Parser;
-use object::{elf::SHT_ARM_ATTRIBUTES, Endianness, File, Object, ObjectSection, SectionKind};
+use arm_attr::globals::tag_from_name;
+use check::CheckArgs;
+use clap::{Parser, Subcommand, ValueEnum};
+use diff::DiffArgs;
+use dump::DumpArgs;
+use get::GetArgs;
+use lint::LintArgs;
+use set::SetArgs;
 
 #[derive(Parser, Debug)]
-#[command(version, about, long_about = None)]
+#[command(version, about, long_about = None, args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct Args {
-    /// Input ELF file
-    #[arg(short, long)]
-    file: PathBuf,
-
-    /// Show default values of attributes not specified in the input
-    #[arg(short = 'd', long)]
-    show_defaults: bool,
+    #[command(subcommand)]
+    command: Option<Command>,
 
-    /// Show target attributes. Enabled by default if -p and -m are not given
-    #[arg(short = 't', long)]
-    show_target: bool,
+    /// Without a subcommand, the arguments of `dump` are accepted
+    #[command(flatten)]
+    dump: Option<DumpArgs>,
+}
 
-    /// Show procedure-call attributes. Enabled by default if -t and -m are not given
-    #[arg(short = 'p', long)]
-    show_pcs: bool,
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Lists the build attributes of an ELF file or archive, or summarizes those of several
+    Dump(DumpArgs),
+    /// Compares the build attributes of two ELF files, and exits with 1 if they differ or 2 on errors
+    Diff(DiffArgs),
+    /// Checks that ELF files and archive members can be linked together, and exits with 1 if they can't
+    Check(CheckArgs),
+    /// Reports suspicious build attributes of ELF files and archive members, and exits with 1 if any are found
+    Lint(LintArgs),
+    /// Prints the value of one attribute of ELF files or archive members
+    Get(GetArgs),
+    /// Sets build attributes of an ELF file, and writes the result to a new file
+    Set(SetArgs),
+}
 
-    /// Show miscellaneous attributes. Enabled by default if -t and -p are not given
-    #[arg(short = 'm', long)]
-    show_misc: bool,
+#[derive(ValueEnum, Clone, Copy, PartialEq, Eq, Debug)]
+enum Format {
+    Text,
+    Json,
+    Yaml,
 }
 
-fn main() -> Result<()> {
+fn main() -> Result<ExitCode> {
     let args = Args::parse();
-    let show_all = !args.show_target && !args.show_pcs && !args.show_misc;
-
-    let data = {
-        let mut file = fs::File::open(args.file)?;
-        let mut data = vec![];
-        file.read_to_end(&mut data)?;
-        data
-    };
-    let file: File<&[u8]> = File::parse(data.as_ref())?;
-    let arm_attrs = file
-        .sections()
-        .find(|s| s.kind() == SectionKind::Elf(SHT_ARM_ATTRIBUTES) && s.name() == Ok(".ARM.attributes"))
-        .ok_or(anyhow!("No attributes section found"))?;
-    let attrs_data = arm_attrs.uncompressed_data()?;
-
-    let build_attrs = BuildAttrs::new(&attrs_data, convert_endian(file.endianness()))?;
-    for section in build_attrs.subsections().map(|s| s.unwrap()) {
-        println!("Vendor: {}", section.vendor_name());
-        if !section.is_aeabi() {
-            continue;
-        }
-        let attributes = section.into_public_attributes()?;
-        println!("    File scope:");
-        println!(
-            "{}",
-            attributes.attributes.display(AttributeDisplayOptions {
-                indent: 8,
-                show_defaults: args.show_defaults,
-                show_target: show_all || args.show_target,
-                show_pcs: show_all || args.show_pcs,
-                show_misc: show_all || args.show_misc
-            })
-        );
+    match args.command {
+        Some(Command::Dump(args)) => dump::run(args),
+        Some(Command::Diff(args)) => diff::run(args),
+        Some(Command::Check(args)) => check::run(args),
+        Some(Command::Lint(args)) => lint::run(args),
+        Some(Command::Get(args)) => get::run(args),
+        Some(Command::Set(args)) => set::run(args),
+        None => dump::run(args.dump.expect("dump arguments are required without a subcommand")),
     }
-
-    Ok(())
 }
 
-fn convert_endian(endian: Endianness) -> Endian {
-    match endian {
-        Endianness::Little => Endian::Little,
-        Endianness::Big => Endian::Big,
+/// Parses a tag name like Tag_ABI_VFP_args, or a tag number.
+fn parse_tag_name(name: &str) -> Result<u32> {
+    match name.parse() {
+        Ok(tag) => Ok(tag),
+        Err(_) => tag_from_name(name).ok_or_else(|| anyhow!("unknown tag name '{name}'")),
     }
 }
+
+fn read_file(path: &Path) -> Result<Vec<u8>> {
+    let mut file = fs::File::open(path)?;
+    let mut data = vec![];
+    file.read_to_end(&mut data)?;
+    Ok(data)
+}
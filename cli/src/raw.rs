@@ -0,0 +1,166 @@
+use std::io::Write;
+
+use anyhow::Result;
+use arm_attr::{
+    error::{ReadError, SubsectionError, TagError},
+    tag::Tag,
+    vendor::{default_parser, VendorParser, VendorTag},
+    BuildAttrs, Subsection,
+};
+
+const BYTES_PER_ROW: usize = 8;
+
+/// Prints every subsection header and tag of the build attributes with its offset in the section, its encoded bytes and
+/// its decoded value. If some bytes can't be parsed, they are printed with a marker under the byte where parsing failed.
+pub fn print_raw(out: &mut impl Write, build_attrs: &BuildAttrs) -> Result<()> {
+    let data = build_attrs.data();
+    print_row(out, 0, &data[..1], &format!("Format version '{}'", data[0] as char))?;
+
+    let mut subsections = build_attrs.subsections();
+    loop {
+        let start = subsections.offset() as usize;
+        match subsections.next() {
+            Some(Ok(subsection)) => print_subsection(out, data, start, subsection)?,
+            Some(Err(SubsectionError::Read {
+                error: ReadError::OutOfBounds,
+                ..
+            })) => {
+                return print_failure(out, data, start, start, "subsection length exceeds the section");
+            }
+            Some(Err(SubsectionError::Read { location, error })) => {
+                let stop = location.offset as usize;
+                return print_failure(out, data, start, stop, &format!("invalid subsection: {error}"));
+            }
+            Some(Err(SubsectionError::Invalid { location, violation })) => {
+                let stop = location.offset as usize;
+                return print_failure(out, data, start, stop, &format!("invalid subsection: {violation}"));
+            }
+            None if start < data.len() => {
+                let stop = subsections.offset() as usize;
+                return print_failure(out, data, start, stop, "truncated subsection header");
+            }
+            None => return Ok(()),
+        }
+    }
+}
+
+fn print_subsection(out: &mut impl Write, data: &[u8], start: usize, subsection: Subsection) -> Result<()> {
+    let vendor_name = subsection.vendor_name().to_string();
+    let base = subsection.offset() as usize;
+    let end = base + subsection.data().len();
+    writeln!(out)?;
+    print_row(
+        out,
+        start,
+        &data[start..start + 4],
+        &format!("Subsection length: {}", end - start),
+    )?;
+    print_row(out, start + 4, &data[start + 4..base], &format!("Vendor: \"{vendor_name}\""))?;
+
+    if subsection.is_aeabi() {
+        // Tags which fail to be read are skipped up to the end of their scope
+        let mut tags = subsection.into_try_public_tag_iter()?.with_recovery();
+        loop {
+            let offset = base + tags.offset() as usize;
+            match tags.next() {
+                Some(Ok((_, tag))) => {
+                    let text = public_tag_text(&tag, base);
+                    print_row(out, offset, &data[offset..base + tags.offset() as usize], &text)?;
+                }
+                Some(Err(err)) => {
+                    let message = match err.error {
+                        TagError::Read(err) => err.to_string(),
+                        err => err.to_string(),
+                    };
+                    let (start, stop) = (err.start as usize, err.location.offset as usize);
+                    print_failure(out, &data[..end], start, stop, &message)?;
+                    let skipped = base + tags.offset() as usize;
+                    if skipped > stop + 1 && skipped < end {
+                        writeln!(out, "{:#06x}  skipped to the end of the scope", skipped)?;
+                    }
+                }
+                None => return Ok(()),
+            }
+        }
+    }
+
+    // The vendor tag iterator stops at the first tag it fails to read, and is left at the byte where reading it stopped
+    let parser = default_parser(&vendor_name);
+    let mut tags = subsection.into_vendor_tag_iter(parser);
+    let (last, stop) = loop {
+        let offset = base + tags.offset() as usize;
+        let Some((_, tag)) = tags.next() else {
+            break (offset, tags.offset());
+        };
+        let text = vendor_tag_text(&tag, parser, base);
+        print_row(out, offset, &data[offset..base + tags.offset() as usize], &text)?;
+    };
+    if last < end {
+        return print_failure(out, &data[..end], last, base + stop as usize, "failed to read tag");
+    }
+    Ok(())
+}
+
+fn public_tag_text(tag: &Tag, base: usize) -> String {
+    match tag {
+        Tag::File { end_offset } => format!("Tag_File, ends at {:#06x}", base + *end_offset as usize),
+        Tag::Section { end_offset, sections } => {
+            format!("Tag_Section {sections:?}, ends at {:#06x}", base + *end_offset as usize)
+        }
+        Tag::Symbol { end_offset, symbols } => {
+            format!("Tag_Symbol {symbols:?}, ends at {:#06x}", base + *end_offset as usize)
+        }
+        Tag::Unknown { tag: number, value } => format!("Tag {number}: {value}"),
+        _ => format!("{}: {tag}", tag.name().unwrap_or_default()),
+    }
+}
+
+fn vendor_tag_text(tag: &VendorTag, parser: &dyn VendorParser, base: usize) -> String {
+    match tag {
+        VendorTag::File { end_offset } => format!("Tag_File, ends at {:#06x}", base + *end_offset as usize),
+        VendorTag::Section { end_offset, sections } => {
+            format!("Tag_Section {sections:?}, ends at {:#06x}", base + *end_offset as usize)
+        }
+        VendorTag::Symbol { end_offset, symbols } => {
+            format!("Tag_Symbol {symbols:?}, ends at {:#06x}", base + *end_offset as usize)
+        }
+        VendorTag::Attribute { tag, value } => match parser.tag_name(*tag) {
+            Some(name) => format!("{name}: {value}"),
+            None => format!("Tag {tag}: {value}"),
+        },
+    }
+}
+
+/// Prints `bytes` in rows of hex, the first of which is followed by `text`.
+fn print_row(out: &mut impl Write, offset: usize, bytes: &[u8], text: &str) -> Result<()> {
+    let mut rows = bytes.chunks(BYTES_PER_ROW);
+    writeln!(out, "{offset:#06x}  {:<23}  {text}", hex(rows.next().unwrap_or_default()))?;
+    for (i, row) in rows.enumerate() {
+        writeln!(out, "{:#06x}  {}", offset + (i + 1) * BYTES_PER_ROW, hex(row))?;
+    }
+    Ok(())
+}
+
+/// Prints the rows of bytes from `start` up to the row of the byte at `stop`, and marks that byte with `message`. A
+/// `stop` at the end of the data is marked past the last byte.
+fn print_failure(out: &mut impl Write, data: &[u8], start: usize, stop: usize, message: &str) -> Result<()> {
+    let stop = stop.min(data.len());
+    let end = (stop - (stop - start) % BYTES_PER_ROW + BYTES_PER_ROW).min(data.len());
+    let rows = data[start..end].chunks(BYTES_PER_ROW);
+    for (i, row) in rows.enumerate() {
+        writeln!(out, "{:#06x}  {}", start + i * BYTES_PER_ROW, hex(row))?;
+    }
+    let column = (stop - start) % BYTES_PER_ROW;
+    let message = if stop == data.len() {
+        format!("{message}: unexpected end of data at {stop:#06x}")
+    } else {
+        format!("{message}: parsing failed at {stop:#06x}")
+    };
+    writeln!(out, "{:width$}^^ {message}", "", width = 8 + column * 3)?;
+    Ok(())
+}
+
+fn hex(bytes: &[u8]) -> String {
+    let hex = bytes.iter().map(|byte| format!("{byte:02x}"));
+    hex.collect::<Vec<_>>().join(" ")
+}
@@ -0,0 +1,298 @@
+use std::io::{self, Write};
+
+use anyhow::Result;
+use arm_attr::{
+    enums::*,
+    tag::{Tag, UnknownValue},
+    vendor::{GenericParser, VendorTag, VendorValue},
+    BuildAttrs,
+};
+
+/// Tag_MPextension_use_OLD, which readelf calls Tag_MPextension_use_legacy
+const TAG_MP_EXTENSION_USE_LEGACY: u32 = 70;
+
+/// Prints build attributes the way `readelf -A` of GNU binutils 2.40 does, so that the output of both tools can be compared.
+/// Attributes are printed in the order they are stored.
+pub fn print_build_attrs(out: &mut impl Write, build_attrs: &BuildAttrs) -> Result<()> {
+    for subsection in build_attrs.subsections() {
+        let subsection = subsection?;
+        writeln!(out, "Attribute Section: {}", subsection.vendor_name())?;
+        if !subsection.is_aeabi() {
+            // readelf decodes all vendor subsections by the odd/even convention
+            for (_, tag) in subsection.into_vendor_tag_iter(&GenericParser) {
+                match tag {
+                    VendorTag::File { .. } => writeln!(out, "File Attributes")?,
+                    VendorTag::Section { sections, .. } => print_scope(out, "Section", &sections)?,
+                    VendorTag::Symbol { symbols, .. } => print_scope(out, "Symbol", &symbols)?,
+                    VendorTag::Attribute { tag, value } => match value {
+                        VendorValue::Uleb128(value) => writeln!(out, "  Tag_unknown_{tag}: {value} ({value:#x})")?,
+                        VendorValue::String(_) | VendorValue::Uleb128String(..) => {
+                            writeln!(out, "  Tag_unknown_{tag}: {value}")?
+                        }
+                    },
+                }
+            }
+            continue;
+        }
+        for (_, tag) in subsection.into_public_tag_iter()? {
+            match &tag {
+                Tag::File { .. } => writeln!(out, "File Attributes")?,
+                Tag::Section { sections, .. } => print_scope(out, "Section", sections)?,
+                Tag::Symbol { symbols, .. } => print_scope(out, "Symbol", symbols)?,
+                _ => writeln!(out, "  {}: {}", tag_name(&tag), tag_value(&tag))?,
+            }
+        }
+    }
+    Ok(())
+}
+
+fn print_scope(out: &mut impl Write, kind: &str, indices: &[u32]) -> io::Result<()> {
+    write!(out, "{kind} Attributes:")?;
+    for index in indices {
+        write!(out, " {index}")?;
+    }
+    writeln!(out)
+}
+
+fn tag_name(tag: &Tag) -> String {
+    match tag {
+        Tag::FramePointerUse(_) => format!("Tag_unknown_{}", tag.raw_tag()),
+        Tag::Unknown { tag, .. } if *tag == TAG_MP_EXTENSION_USE_LEGACY => "Tag_MPextension_use_legacy".into(),
+        Tag::Unknown { tag, .. } => format!("Tag_unknown_{tag}"),
+        _ => tag.name().unwrap_or_default().into(),
+    }
+}
+
+/// Formats a value by its `Display` impl, except for the listed variants which `readelf -A` of GNU binutils 2.40 names
+/// differently. Unknown values are printed like readelf does.
+macro_rules! readelf_value {
+    ($value:expr, $ty:ident { $($variant:ident => $name:expr),* $(,)? }) => {
+        match $value {
+            $($ty::$variant => $name.to_string(),)*
+            $ty::Unknown(value) => unknown(value),
+            #[allow(unreachable_patterns)]
+            value => value.to_string(),
+        }
+    };
+}
+
+fn tag_value(tag: &Tag) -> String {
+    match *tag {
+        Tag::CpuRawName(_) => tag.to_string(),
+        Tag::CpuName(x) => format!("\"{}\"", x.as_str()),
+        Tag::CpuArch(x) => cpu_arch(x),
+        Tag::CpuArchProfile(x) => readelf_value!(x, CpuArchProfile {
+            NotApplicable => "None",
+            Application => "Application",
+            RealTime => "Realtime",
+            Microcontroller => "Microcontroller",
+            Classic => "Application or Realtime",
+        }),
+        Tag::ArmIsaUse(x) => readelf_value!(x, ArmIsaUse { None => "No", Allowed => "Yes" }),
+        Tag::ThumbIsaUse(x) => readelf_value!(x, ThumbIsaUse {
+            None => "No",
+            Allowed16Bit => "Thumb-1",
+            Allowed32Bit => "Thumb-2",
+            Allowed => "Yes",
+        }),
+        Tag::FpArch(x) => readelf_value!(x, FpArch {
+            None => "No",
+            V1 => "VFPv1",
+            V2 => "VFPv2",
+            V3 => "VFPv3",
+            V3Light => "VFPv3-D16",
+            V4 => "VFPv4",
+            V4Light => "VFPv4-D16",
+            V8A => "FP for ARMv8",
+            V8ALight => "FPv5/FP-D16 for ARMv8",
+        }),
+        Tag::WmmxArch(x) => readelf_value!(x, WmmxArch { None => "No", V1 => "WMMXv1", V2 => "WMMXv2" }),
+        Tag::AsimdArch(x) => readelf_value!(x, AsimdArch {
+            None => "No",
+            V1 => "NEONv1",
+            V2 => "NEONv1 with Fused-MAC",
+            V8A => "NEON for ARMv8",
+            V8_1A => "NEON for ARMv8.1",
+        }),
+        Tag::PcsConfig(x) => readelf_value!(x, PcsConfig {
+            PalmOs2004 => "PalmOS 2004",
+            PalmOsFuture => "PalmOS (reserved)",
+            SymbianOs2004 => "SymbianOS 2004",
+            SymbianOsFuture => "SymbianOS (reserved)",
+        }),
+        Tag::AbiPcsR9Use(x) => readelf_value!(x, AbiPcsR9Use { TlsPointer => "TLS", None => "Unused" }),
+        Tag::AbiPcsRwData(x) => readelf_value!(x, AbiPcsRwData { None => "None" }),
+        Tag::AbiPcsRoData(x) => readelf_value!(x, AbiPcsRoData { None => "None" }),
+        Tag::AbiPcsGotUse(x) => readelf_value!(x, AbiPcsGotUse {
+            None => "None",
+            Direct => "direct",
+            Indirect => "GOT-indirect",
+        }),
+        Tag::AbiPcsWcharT(x) => match x {
+            // readelf's table has placeholders for the sizes in between
+            AbiPcsWcharT::Unknown(value @ (1 | 3)) => format!("??? {value}"),
+            _ => readelf_value!(x, AbiPcsWcharT { None => "None", Size2 => "2", Size4 => "4" }),
+        },
+        Tag::AbiFpRounding(x) => readelf_value!(x, AbiFpRounding { Nearest => "Unused", RunTime => "Needed" }),
+        Tag::AbiFpDenormal(x) => readelf_value!(x, AbiFpDenormal {
+            DontCare => "Unused",
+            Ieee754 => "Needed",
+            PreserveSign => "Sign only",
+        }),
+        Tag::AbiFpExceptions(x) => readelf_value!(x, AbiFpExceptions { None => "Unused", CheckInexact => "Needed" }),
+        Tag::AbiFpUserExceptions(x) => readelf_value!(x, AbiFpUserExceptions { None => "Unused", Enabled => "Needed" }),
+        Tag::AbiFpNumberModel(x) => readelf_value!(x, AbiFpNumberModel {
+            None => "Unused",
+            Normal => "Finite",
+            InfNaN => "RTABI",
+            All => "IEEE 754",
+        }),
+        Tag::AbiAlignNeeded(x) => match x {
+            AbiAlignNeeded::Reserved => "??? 3".into(),
+            AbiAlignNeeded::Align2n(value) => extended_alignment(value),
+            AbiAlignNeeded::Unknown(value) => unknown(value),
+            _ => x.to_string(),
+        },
+        Tag::AbiAlignPreserved(x) => match x {
+            AbiAlignPreserved::Align8 => "8-byte, except leaf SP".into(),
+            AbiAlignPreserved::Align4 => "8-byte".into(),
+            AbiAlignPreserved::Reserved => "??? 3".into(),
+            AbiAlignPreserved::Align2n(value) => extended_alignment(value),
+            AbiAlignPreserved::Unknown(value) => unknown(value),
+            _ => x.to_string(),
+        },
+        Tag::AbiEnumSize(x) => readelf_value!(x, AbiEnumSize {
+            None => "Unused",
+            SmallestSize => "small",
+            Always32 => "int",
+            Visible32 => "forced to int",
+        }),
+        Tag::AbiHardFpUse(x) => readelf_value!(x, AbiHardFpUse {
+            Implied => "As Tag_FP_arch",
+            DerivedSingle => "SP only",
+            ImpliedOld => "Deprecated",
+        }),
+        Tag::AbiVfpArgs(x) => readelf_value!(x, AbiVfpArgs {
+            Base => "AAPCS",
+            Vfp => "VFP registers",
+            Toolchain => "custom",
+            BaseVfp => "compatible",
+        }),
+        Tag::AbiWmmxArgs(x) => readelf_value!(x, AbiWmmxArgs {
+            Base => "AAPCS",
+            Intel => "WMMX registers",
+            Toolchain => "custom",
+        }),
+        Tag::AbiOptGoals(x) => readelf_value!(x, AbiOptGoals {
+            FavorSpeed => "Prefer Speed",
+            OptimizeSpeed => "Aggressive Speed",
+            FavorSize => "Prefer Size",
+            OptimizeSize => "Aggressive Size",
+            FavorDebug => "Prefer Debug",
+            OptimizeDebug => "Aggressive Debug",
+        }),
+        Tag::AbiFpOptGoals(x) => readelf_value!(x, AbiFpOptGoals {
+            FavorSpeed => "Prefer Speed",
+            OptimizeSpeed => "Aggressive Speed",
+            FavorSize => "Prefer Size",
+            OptimizeSize => "Aggressive Size",
+            FavorAccuracy => "Prefer Accuracy",
+            OptimizeAccuracy => "Aggressive Accuracy",
+        }),
+        Tag::Compat(x) => {
+            let vendor = match x {
+                Compat::Always => "",
+                Compat::ByToolchain(vendor) | Compat::Private { vendor, .. } => vendor,
+            };
+            format!("flag = {}, vendor = {vendor}", x.value())
+        }
+        Tag::CpuUnalignedAccess(x) => readelf_value!(x, CpuUnalignedAccess { Allowed => "v6" }),
+        // readelf doesn't know the ARMv8.2-A value
+        Tag::FpHpExt(x) => readelf_value!(x, FpHpExt {
+            IfExists => "Not Allowed",
+            VfpV3 => "Allowed",
+            ArmV8_2A => unknown(x.value()),
+        }),
+        Tag::AbiFp16BitFormat(x) => readelf_value!(x, AbiFp16BitFormat {
+            Ieee754 => "IEEE 754",
+            Alternative => "Alternative Format",
+        }),
+        Tag::MpExtUse(x) => readelf_value!(x, MpExtUse { None => "Not Allowed" }),
+        Tag::DivUse(x) => readelf_value!(x, DivUse {
+            IfExists => "Allowed in Thumb-ISA, v7-R or v7-M",
+            None => "Not allowed",
+            Allowed => "Allowed in v7-A with integer division extension",
+        }),
+        Tag::DspExt(x) => readelf_value!(x, DspExt { IfExists => "Follow architecture" }),
+        Tag::MveArch(x) => readelf_value!(x, MveArch {
+            None => "No MVE",
+            Int => "MVE Integer only",
+            IntFloat => "MVE Integer and FP",
+        }),
+        Tag::PacExt(x) => readelf_value!(x, PacExt {
+            None => "No PAC/AUT instructions",
+            OnlyNopSpace => "PAC/AUT instructions permitted in the NOP space",
+            Allowed => "PAC/AUT instructions permitted in the NOP and in the non-NOP space",
+        }),
+        Tag::BtiExt(x) => readelf_value!(x, BtiExt {
+            None => "BTI instructions not permitted",
+            OnlyNopSpace => "BTI instructions permitted in the NOP space",
+            Allowed => "BTI instructions permitted in the NOP and in the non-NOP space",
+        }),
+        Tag::NoDefaults => "True".into(),
+        Tag::AlsoCompatWith(ref x) => match x {
+            AlsoCompatWith::Arch(arch) => cpu_arch(*arch),
+            _ => "???".into(),
+        },
+        Tag::Conform(x) => format!("\"{}\"", x.as_str()),
+        Tag::T2EeUse(x) => readelf_value!(x, T2EeUse { None => "Not Allowed" }),
+        Tag::VirtualUse(x) => readelf_value!(x, VirtualUse {
+            None => "Not Allowed",
+            VExts => "Virtualization Extensions",
+            TrustZoneVExts => "TrustZone and Virtualization Extensions",
+        }),
+        Tag::FramePointerUse(x) => {
+            let value = x.value();
+            format!("{value} ({value:#x})")
+        }
+        Tag::BtiUse(x) => readelf_value!(x, BtiUse {
+            None => "Compiled without branch target enforcement",
+            Enabled => "Compiled with branch target enforcement",
+        }),
+        Tag::PacretUse(x) => readelf_value!(x, PacretUse {
+            None => "Compiled without return address signing and authentication",
+            Enabled => "Compiled with return address signing and authentication",
+        }),
+        Tag::Unknown { tag, value } => match value {
+            UnknownValue::Uleb128(value) if tag == TAG_MP_EXTENSION_USE_LEGACY => match u32::try_from(value) {
+                Ok(value) => tag_value(&Tag::MpExtUse(value.into())),
+                Err(_) => format!("??? ({value})"),
+            },
+            UnknownValue::Uleb128(value) => format!("{value} ({value:#x})"),
+            UnknownValue::String(_) => value.to_string(),
+        },
+        Tag::File { .. } | Tag::Section { .. } | Tag::Symbol { .. } => unreachable!("scope tags have no value"),
+    }
+}
+
+/// readelf names architectures like `Display` without the "ARM" prefix, except for the first ARMv8-A and ARMv9-A.
+fn cpu_arch(arch: CpuArch) -> String {
+    match arch {
+        CpuArch::V8A => "v8".into(),
+        CpuArch::V9A => "v9".into(),
+        CpuArch::Unknown(value) => unknown(value),
+        _ => {
+            let name = arch.to_string();
+            name.strip_prefix("ARM").unwrap_or(&name).into()
+        }
+    }
+}
+
+fn unknown(value: u32) -> String {
+    format!("??? ({value})")
+}
+
+/// Tag_ABI_align_needed and Tag_ABI_align_preserved share their encoding of extended alignments.
+fn extended_alignment(value: u32) -> String {
+    format!("8-byte and up to {}-byte extended", 1u32 << value)
+}
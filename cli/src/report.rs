@@ -0,0 +1,304 @@
+use arm_attr::{
+    enums::{AlsoCompatWith, Compat},
+    globals::*,
+    tag::UnknownValue,
+    vendor::{default_parser, VendorTag, VendorValue},
+    Attributes, Subsection,
+};
+use object::{Object, ObjectSection, ObjectSymbol, SectionIndex, SymbolIndex};
+use serde::Serialize;
+
+/// Structured form of the CLI output, used by the JSON and YAML formats.
+#[derive(Serialize)]
+pub struct Report {
+    pub subsections: Vec<SubsectionReport>,
+}
+
+#[derive(Serialize)]
+pub struct SubsectionReport {
+    pub vendor: String,
+    pub scopes: Vec<ScopeReport>,
+}
+
+#[derive(Serialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum ScopeKind {
+    File,
+    Section,
+    Symbol,
+}
+
+#[derive(Serialize)]
+pub struct ScopeReport {
+    pub scope: ScopeKind,
+    /// Section indices or symbol values of the scope
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub indices: Vec<u32>,
+    /// Section or symbol names resolved from `indices`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub names: Vec<String>,
+    /// Whether the attributes were inherited from the enclosing scope
+    pub inherited: bool,
+    pub attributes: Vec<AttributeReport>,
+}
+
+#[derive(Serialize, Clone)]
+pub struct AttributeReport {
+    pub tag: u32,
+    pub name: Option<&'static str>,
+    pub value: Value,
+    pub meaning: String,
+    /// Whether the attribute was not specified, and the value is its default
+    pub default: bool,
+}
+
+#[derive(Serialize, Clone, PartialEq, Eq, Debug)]
+#[serde(untagged)]
+pub enum Value {
+    Number(u64),
+    String(String),
+    NumberString(u64, String),
+    Flag(bool),
+}
+
+impl Report {
+    pub fn new() -> Self {
+        Self { subsections: vec![] }
+    }
+
+    pub fn add_subsection(
+        &mut self,
+        object: &object::File,
+        subsection: Subsection,
+        show_defaults: bool,
+    ) -> anyhow::Result<()> {
+        let vendor = subsection.vendor_name().to_string();
+        let scopes = if subsection.is_aeabi() {
+            public_scopes(object, subsection, show_defaults)?
+        } else {
+            vendor_scopes(subsection)
+        };
+        self.subsections.push(SubsectionReport { vendor, scopes });
+        Ok(())
+    }
+}
+
+fn public_scopes(object: &object::File, subsection: Subsection, show_defaults: bool) -> anyhow::Result<Vec<ScopeReport>> {
+    let file = subsection.into_public_attributes()?;
+    let mut scopes = vec![ScopeReport {
+        scope: ScopeKind::File,
+        indices: vec![],
+        names: vec![],
+        inherited: false,
+        attributes: attribute_reports(&file.attributes, show_defaults),
+    }];
+    for (sections, section) in &file.sections {
+        scopes.push(ScopeReport {
+            scope: ScopeKind::Section,
+            indices: sections.clone(),
+            names: sections.iter().map(|&index| section_name(object, index)).collect(),
+            inherited: section.inherited,
+            attributes: attribute_reports(&section.attributes, show_defaults),
+        });
+        for (symbols, symbol) in &section.symbols {
+            scopes.push(ScopeReport {
+                scope: ScopeKind::Symbol,
+                indices: symbols.clone(),
+                names: symbols.iter().map(|&value| symbol_name(object, value)).collect(),
+                inherited: symbol.inherited,
+                attributes: attribute_reports(&symbol.attributes, show_defaults),
+            });
+        }
+    }
+    Ok(scopes)
+}
+
+fn vendor_scopes(subsection: Subsection) -> Vec<ScopeReport> {
+    let parser = default_parser(subsection.vendor_name());
+    let mut scopes: Vec<ScopeReport> = vec![];
+    for (_, tag) in subsection.into_vendor_tag_iter(parser) {
+        let (scope, indices) = match tag {
+            VendorTag::File { end_offset: _ } => (ScopeKind::File, vec![]),
+            VendorTag::Section { end_offset: _, sections } => (ScopeKind::Section, sections),
+            VendorTag::Symbol { end_offset: _, symbols } => (ScopeKind::Symbol, symbols),
+            VendorTag::Attribute { tag, value } => {
+                let Some(scope) = scopes.last_mut() else { continue };
+                scope.attributes.push(AttributeReport {
+                    tag,
+                    name: parser.tag_name(tag),
+                    value: match value {
+                        VendorValue::Uleb128(value) => Value::Number(value),
+                        VendorValue::String(value) => Value::String(value.to_string()),
+                        VendorValue::Uleb128String(value, string) => Value::NumberString(value, string.to_string()),
+                    },
+                    meaning: value.to_string(),
+                    default: false,
+                });
+                continue;
+            }
+        };
+        scopes.push(ScopeReport {
+            scope,
+            indices,
+            names: vec![],
+            inherited: false,
+            attributes: vec![],
+        });
+    }
+    scopes
+}
+
+pub fn attribute_reports(attrs: &Attributes, show_defaults: bool) -> Vec<AttributeReport> {
+    let mut reports = vec![];
+    let mut push = |tag: u8, name: &'static str, value: Option<(Value, String)>, default: (Value, String)| {
+        let (value, meaning, default) = match value {
+            Some((value, meaning)) => (value, meaning, false),
+            None if show_defaults => (default.0, default.1, true),
+            None => return,
+        };
+        reports.push(AttributeReport {
+            tag: tag.into(),
+            name: Some(name),
+            value,
+            meaning,
+            default,
+        });
+    };
+
+    macro_rules! numeric {
+        ($field:ident, $tag:ident) => {
+            push(
+                $tag,
+                stringify!($tag),
+                attrs.$field.map(|x| (Value::Number(x.value().into()), x.to_string())),
+                {
+                    let x = attrs.$field.unwrap_or_default();
+                    (Value::Number(x.value().into()), x.to_string())
+                },
+            )
+        };
+    }
+    macro_rules! string {
+        ($field:ident, $tag:ident) => {
+            push(
+                $tag,
+                stringify!($tag),
+                attrs
+                    .$field
+                    .map(|x| (Value::String(x.as_str().to_string()), x.to_string())),
+                {
+                    let x = attrs.$field.unwrap_or_default();
+                    (Value::String(x.as_str().to_string()), x.to_string())
+                },
+            )
+        };
+    }
+
+    push(
+        Tag_CPU_raw_name,
+        "Tag_CPU_raw_name",
+        attrs.cpu_raw_name.map(|x| (Value::String(x.to_string()), x.to_string())),
+        (Value::String(String::new()), String::new()),
+    );
+    string!(cpu_name, Tag_CPU_name);
+    numeric!(cpu_arch, Tag_CPU_arch);
+    numeric!(cpu_arch_profile, Tag_CPU_arch_profile);
+    numeric!(arm_isa_use, Tag_ARM_ISA_use);
+    numeric!(thumb_isa_use, Tag_THUMB_ISA_use);
+    numeric!(fp_arch, Tag_FP_arch);
+    numeric!(wmmx_arch, Tag_WMMX_arch);
+    numeric!(asimd_arch, Tag_Advanced_SIMD_arch);
+    numeric!(mve_arch, Tag_MVE_arch);
+    numeric!(fp_hp_ext, Tag_FP_HP_extension);
+    numeric!(cpu_unaligned_access, Tag_CPU_unaligned_access);
+    numeric!(t2ee_use, Tag_T2EE_use);
+    numeric!(virtual_use, Tag_Virtualization_use);
+    numeric!(mp_ext_use, Tag_MPextension_use);
+    numeric!(div_use, Tag_DIV_use);
+    numeric!(dsp_ext, Tag_DSP_extension);
+    numeric!(pac_ext, Tag_PAC_extension);
+    numeric!(bti_ext, Tag_BTI_extension);
+    numeric!(pcs_config, Tag_PCS_config);
+    numeric!(abi_pcs_r9_use, Tag_ABI_PCS_R9_use);
+    numeric!(abi_pcs_rw_data, Tag_ABI_PCS_RW_data);
+    numeric!(abi_pcs_ro_data, Tag_ABI_PCS_RO_data);
+    numeric!(abi_pcs_got_use, Tag_ABI_PCS_GOT_use);
+    numeric!(abi_pcs_wchar_t, Tag_ABI_PCS_wchar_t);
+    numeric!(abi_enum_size, Tag_ABI_enum_size);
+    numeric!(abi_align_needed, Tag_ABI_align_needed);
+    numeric!(abi_align_preserved, Tag_ABI_align_preserved);
+    numeric!(abi_fp_rounding, Tag_ABI_FP_rounding);
+    numeric!(abi_fp_denormal, Tag_ABI_FP_denormal);
+    numeric!(abi_fp_exceptions, Tag_ABI_FP_exceptions);
+    numeric!(abi_fp_user_exceptions, Tag_ABI_FP_user_exceptions);
+    numeric!(abi_fp_number_model, Tag_ABI_FP_number_model);
+    numeric!(abi_fp_16bit_format, Tag_ABI_FP_16bit_format);
+    numeric!(abi_hardfp_use, Tag_ABI_HardFP_use);
+    numeric!(abi_vfp_args, Tag_ABI_VFP_args);
+    numeric!(abi_wmmx_args, Tag_ABI_WMMX_args);
+    numeric!(frame_pointer_use, Tag_FramePointer_use);
+    numeric!(bti_use, Tag_BTI_use);
+    numeric!(pacret_use, Tag_PACRET_use);
+    numeric!(abi_opt_goals, Tag_ABI_optimization_goals);
+    numeric!(abi_fp_opt_goals, Tag_ABI_FP_optimization_goals);
+    push(
+        Tag_compatibility,
+        "Tag_compatibility",
+        attrs.compat.map(|x| {
+            let value = match x {
+                Compat::Always => Value::Number(0),
+                Compat::ByToolchain(vendor) | Compat::Private { flag: _, vendor } => {
+                    Value::NumberString(x.value().into(), vendor.to_string())
+                }
+            };
+            (value, x.to_string())
+        }),
+        (Value::Number(0), Compat::default().to_string()),
+    );
+    push(
+        Tag_also_compatible_with,
+        "Tag_also_compatible_with",
+        attrs
+            .also_compat_with
+            .as_ref()
+            .map(|x| (Value::String(x.to_string()), x.to_string())),
+        (Value::String(String::new()), AlsoCompatWith::default().to_string()),
+    );
+    string!(conform, Tag_conformance);
+    if attrs.no_defaults {
+        push(
+            Tag_nodefaults,
+            "Tag_nodefaults",
+            Some((Value::Flag(true), "No defaults".to_string())),
+            (Value::Flag(false), String::new()),
+        );
+    }
+
+    for (&tag, value) in &attrs.unknown {
+        reports.push(AttributeReport {
+            tag,
+            name: None,
+            meaning: value.to_string(),
+            value: match value {
+                UnknownValue::Uleb128(value) => Value::Number(*value),
+                UnknownValue::String(value) => Value::String(value.to_string()),
+            },
+            default: false,
+        });
+    }
+    reports
+}
+
+pub fn section_name(object: &object::File, index: u32) -> String {
+    match object.section_by_index(SectionIndex(index as usize)).and_then(|s| s.name()) {
+        Ok(name) => name.to_string(),
+        Err(_) => format!("#{index}"),
+    }
+}
+
+pub fn symbol_name(object: &object::File, value: u32) -> String {
+    match object.symbol_by_index(SymbolIndex(value as usize)).and_then(|s| s.name()) {
+        Ok(name) if !name.is_empty() => name.to_string(),
+        _ => format!("#{value}"),
+    }
+}
@@ -0,0 +1,156 @@
+use std::{fs, path::PathBuf, process::ExitCode};
+
+use anyhow::{anyhow, bail, Context, Result};
+use arm_attr::{elf32::replace_attributes_section, tag::Tag, write::BuildAttrsWriter, BuildAttrs, File, SectionGroup};
+use clap::Args;
+use object::{Object, ObjectSection, ObjectSymbol};
+
+use crate::{parse_tag_name, read_file};
+
+#[derive(Args, Debug)]
+pub struct SetArgs {
+    /// Input ELF file
+    input: PathBuf,
+
+    /// Output ELF file, which may be the input file
+    #[arg(short, long)]
+    output: PathBuf,
+
+    /// Attribute to set as NAME=VALUE, where NAME is a tag name like Tag_ABI_VFP_args or a tag number. Can be given
+    /// several times
+    #[arg(long = "tag", value_name = "NAME=VALUE", required = true)]
+    tags: Vec<String>,
+
+    /// Scope to set the attributes in: "file", "section:LIST" or "symbol:LIST", where LIST is a comma-separated list of
+    /// section or symbol indices or names
+    #[arg(long, default_value = "file")]
+    scope: String,
+}
+
+pub enum Scope {
+    File,
+    Section(Vec<u32>),
+    Symbol(Vec<u32>),
+}
+
+pub fn run(args: SetArgs) -> Result<ExitCode> {
+    let data = read_file(&args.input)?;
+    let object = object::File::parse(&*data)?;
+    let scope = parse_scope(&object, &args.scope)?;
+    let values = args.tags.iter().map(|tag| parse_tag(tag)).collect::<Result<Vec<_>>>()?;
+    let tags = values
+        .iter()
+        .map(|(tag, value)| new_tag(*tag, value))
+        .collect::<Result<Vec<_>>>()?;
+
+    let build_attrs = BuildAttrs::from_elf32(&data)?;
+    let mut writer = BuildAttrsWriter::new(build_attrs.endian());
+    let mut has_aeabi = false;
+    for subsection in build_attrs.subsections() {
+        let subsection = subsection?;
+        if !subsection.is_aeabi() {
+            writer.add_subsection(subsection.vendor_name(), subsection.data());
+            continue;
+        }
+        let mut file = subsection.into_public_attributes()?;
+        set_tags(&mut file, &scope, &tags)?;
+        writer.add_public_attributes(&file);
+        has_aeabi = true;
+    }
+    if !has_aeabi {
+        let mut file = File::default();
+        set_tags(&mut file, &scope, &tags)?;
+        writer.add_public_attributes(&file);
+    }
+
+    let out = replace_attributes_section(&data, &writer.finish())?;
+    fs::write(&args.output, out).with_context(|| format!("failed to write {}", args.output.display()))?;
+    Ok(ExitCode::SUCCESS)
+}
+
+fn set_tags<'a>(file: &mut File<'a>, scope: &Scope, tags: &[Tag<'a>]) -> Result<()> {
+    match scope {
+        Scope::File => tags.iter().for_each(|tag| file.attributes.set(tag.clone())),
+        Scope::Section(sections) => {
+            // A new section scope gets the attributes it would have inherited, so that only the given tags change
+            let inherited = (!file.attributes.no_defaults).then(|| file.attributes.clone());
+            let section = file.sections.entry(sections.clone()).or_insert_with(|| SectionGroup {
+                attributes: inherited.unwrap_or_default(),
+                ..Default::default()
+            });
+            section.attributes.no_defaults = false;
+            section.inherited = false;
+            tags.iter().for_each(|tag| section.attributes.set(tag.clone()));
+        }
+        Scope::Symbol(symbols) => {
+            let mut found = false;
+            for symbol in file
+                .sections
+                .values_mut()
+                .filter_map(|section| section.symbols.get_mut(symbols))
+            {
+                symbol.inherited = false;
+                tags.iter().for_each(|tag| symbol.attributes.set(tag.clone()));
+                found = true;
+            }
+            if !found {
+                bail!("no symbol scope for symbols {symbols:?}");
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Parses "file", "section:LIST" or "symbol:LIST", where LIST is a comma-separated list of section or symbol indices or
+/// names in `object`.
+pub fn parse_scope(object: &object::File, scope: &str) -> Result<Scope> {
+    if scope == "file" {
+        return Ok(Scope::File);
+    }
+    let (kind, list) = scope
+        .split_once(':')
+        .ok_or_else(|| anyhow!("invalid scope '{scope}', expected file, section:LIST or symbol:LIST"))?;
+    let items = list.split(',');
+    match kind {
+        "section" => {
+            let indices = items.map(|item| match item.parse() {
+                Ok(index) => Ok(index),
+                Err(_) => object
+                    .section_by_name(item)
+                    .map(|section| section.index().0 as u32)
+                    .ok_or_else(|| anyhow!("no section named '{item}'")),
+            });
+            Ok(Scope::Section(indices.collect::<Result<_>>()?))
+        }
+        "symbol" => {
+            let indices = items.map(|item| match item.parse() {
+                Ok(index) => Ok(index),
+                Err(_) => object
+                    .symbol_by_name(item)
+                    .map(|symbol| symbol.index().0 as u32)
+                    .ok_or_else(|| anyhow!("no symbol named '{item}'")),
+            });
+            Ok(Scope::Symbol(indices.collect::<Result<_>>()?))
+        }
+        _ => bail!("invalid scope '{scope}', expected file, section:LIST or symbol:LIST"),
+    }
+}
+
+/// Parses NAME=VALUE into a tag number and value.
+fn parse_tag(arg: &str) -> Result<(u32, &str)> {
+    let (name, value) = arg
+        .split_once('=')
+        .ok_or_else(|| anyhow!("invalid tag '{arg}', expected NAME=VALUE"))?;
+    Ok((parse_tag_name(name)?, value))
+}
+
+fn new_tag(tag: u32, value: &str) -> Result<Tag<'_>> {
+    let number = match value.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => value.parse().ok(),
+    };
+    number
+        .and_then(|number| Tag::from_uleb128(tag, number))
+        .or_else(|| Tag::from_string(tag, value))
+        .ok_or_else(|| anyhow!("invalid value '{value}' for tag {tag}"))
+}
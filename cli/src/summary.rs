@@ -0,0 +1,95 @@
+use anyhow::Result;
+use arm_attr::{Attributes, BuildAttrs};
+use serde::Serialize;
+
+/// The most important file-scope attributes of an input, as one row of the summary table.
+#[derive(Serialize)]
+pub struct SummaryRow {
+    pub path: String,
+    pub cpu_arch: Option<String>,
+    pub profile: Option<String>,
+    pub fp_arch: Option<String>,
+    pub vfp_args: Option<String>,
+    pub wchar_t: Option<String>,
+    pub enum_size: Option<String>,
+}
+
+const HEADERS: [&str; 7] = ["Path", "CPU arch", "Profile", "FP arch", "VFP args", "wchar_t", "Enum size"];
+
+impl SummaryRow {
+    /// Creates a row from the "aeabi" subsection of the build attributes, if there are any.
+    pub fn new(path: String, build_attrs: Option<&BuildAttrs>) -> Result<Self> {
+        let mut row = Self {
+            path,
+            cpu_arch: None,
+            profile: None,
+            fp_arch: None,
+            vfp_args: None,
+            wchar_t: None,
+            enum_size: None,
+        };
+        let Some(build_attrs) = build_attrs else {
+            return Ok(row);
+        };
+        for subsection in build_attrs.subsections() {
+            let subsection = subsection?;
+            if !subsection.is_aeabi() {
+                continue;
+            }
+            let Attributes {
+                cpu_arch,
+                cpu_arch_profile,
+                fp_arch,
+                abi_vfp_args,
+                abi_pcs_wchar_t,
+                abi_enum_size,
+                ..
+            } = subsection.into_public_attributes()?.attributes;
+            row.cpu_arch = cpu_arch.map(|v| v.to_string());
+            row.profile = cpu_arch_profile.map(|v| v.to_string());
+            row.fp_arch = fp_arch.map(|v| v.to_string());
+            row.vfp_args = abi_vfp_args.map(|v| v.to_string());
+            row.wchar_t = abi_pcs_wchar_t.map(|v| v.to_string());
+            row.enum_size = abi_enum_size.map(|v| v.to_string());
+        }
+        Ok(row)
+    }
+
+    fn cells(&self) -> [&str; 7] {
+        fn cell(value: &Option<String>) -> &str {
+            value.as_deref().unwrap_or("-")
+        }
+        [
+            &self.path,
+            cell(&self.cpu_arch),
+            cell(&self.profile),
+            cell(&self.fp_arch),
+            cell(&self.vfp_args),
+            cell(&self.wchar_t),
+            cell(&self.enum_size),
+        ]
+    }
+}
+
+/// Prints the rows as a table with aligned columns. Attributes which are not present are shown as `-`.
+pub fn print_table(rows: &[SummaryRow]) {
+    let mut widths = HEADERS.map(str::len);
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row.cells()) {
+            *width = (*width).max(cell.len());
+        }
+    }
+    let print_row = |cells: [&str; 7]| {
+        let line = cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        println!("{}", line.trim_end());
+    };
+    print_row(HEADERS);
+    for row in rows {
+        print_row(row.cells());
+    }
+}
@@ -0,0 +1,197 @@
+use core::str;
+
+use crate::{
+    error::{ArchiveError, Elf32Error},
+    BuildAttrs,
+};
+
+const MAGIC: &[u8] = b"!<arch>\n";
+const THIN_MAGIC: &[u8] = b"!<thin>\n";
+const HEADER_SIZE: usize = 60;
+
+/// A static library in the `ar` format, as used by GNU and BSD toolchains. Symbol tables and name tables are recognized
+/// and skipped when iterating over the members.
+pub struct Archive<'a> {
+    data: &'a [u8],
+    thin: bool,
+}
+
+/// A file stored in an [`Archive`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ArchiveMember<'a> {
+    /// Name of the member. For thin archives, this is the path of the member relative to the archive.
+    pub name: &'a str,
+    /// Contents of the member, or `None` if the archive is thin and the member is stored outside of it
+    pub data: Option<&'a [u8]>,
+}
+
+impl<'a> Archive<'a> {
+    pub fn parse(data: &'a [u8]) -> Result<Self, ArchiveError> {
+        let thin = if data.starts_with(MAGIC) {
+            false
+        } else if data.starts_with(THIN_MAGIC) {
+            true
+        } else {
+            return Err(ArchiveError::NotArchive);
+        };
+        Ok(Self { data, thin })
+    }
+
+    /// Returns whether the archive is thin, i.e. only refers to its members by path.
+    pub fn is_thin(&self) -> bool {
+        self.thin
+    }
+
+    pub fn members(&self) -> ArchiveMemberIter<'a> {
+        ArchiveMemberIter {
+            data: self.data,
+            offset: MAGIC.len(),
+            thin: self.thin,
+            names: &[],
+        }
+    }
+
+    /// Iterates over the build attributes of each member using [`BuildAttrs::from_elf32`]. Members which are not ELF files
+    /// or have no `.ARM.attributes` section are skipped.
+    pub fn build_attrs(&self) -> ArchiveBuildAttrsIter<'a> {
+        ArchiveBuildAttrsIter { members: self.members() }
+    }
+}
+
+impl<'a> BuildAttrs<'a> {
+    /// Reads the build attributes of each member of an `ar` archive, see [`Archive::build_attrs`].
+    pub fn from_archive(data: &'a [u8]) -> Result<ArchiveBuildAttrsIter<'a>, ArchiveError> {
+        Ok(Archive::parse(data)?.build_attrs())
+    }
+}
+
+pub struct ArchiveMemberIter<'a> {
+    data: &'a [u8],
+    offset: usize,
+    thin: bool,
+    /// GNU table of names which are too long for the member header
+    names: &'a [u8],
+}
+
+impl<'a> ArchiveMemberIter<'a> {
+    fn next_member(&mut self) -> Result<Option<ArchiveMember<'a>>, ArchiveError> {
+        loop {
+            if self.offset >= self.data.len() {
+                return Ok(None);
+            }
+            let header = self
+                .data
+                .get(self.offset..self.offset + HEADER_SIZE)
+                .ok_or(ArchiveError::InvalidHeader)?;
+            if &header[58..60] != b"`\n" {
+                return Err(ArchiveError::InvalidHeader);
+            }
+            let raw_name = trim_end(&header[..16]);
+            let size = parse_decimal(&header[48..58]).ok_or(ArchiveError::InvalidHeader)?;
+
+            let start = self.offset + HEADER_SIZE;
+            let special = matches!(raw_name, b"/" | b"//" | b"/SYM64/");
+            // Members of thin archives are stored outside of the archive, except for symbol and name tables
+            let stored_size = if self.thin && !special { 0 } else { size };
+            let end = start.checked_add(stored_size).ok_or(ArchiveError::InvalidHeader)?;
+            let mut data = self.data.get(start..end).ok_or(ArchiveError::InvalidHeader)?;
+            self.offset = end + (end & 1);
+
+            let name = match raw_name {
+                // GNU symbol tables
+                b"/" | b"/SYM64/" => continue,
+                b"//" => {
+                    self.names = data;
+                    continue;
+                }
+                _ if raw_name.starts_with(b"#1/") => {
+                    // BSD names are stored at the start of the data
+                    let length = parse_decimal(&raw_name[3..]).ok_or(ArchiveError::InvalidName)?;
+                    if length > data.len() {
+                        return Err(ArchiveError::InvalidName);
+                    }
+                    let (name, rest) = data.split_at(length);
+                    data = rest;
+                    let name = &name[..name.iter().position(|&b| b == 0).unwrap_or(name.len())];
+                    if name.starts_with(b"__.SYMDEF") {
+                        continue;
+                    }
+                    name
+                }
+                _ if raw_name.starts_with(b"/") => {
+                    let offset = parse_decimal(&raw_name[1..]).ok_or(ArchiveError::InvalidName)?;
+                    let names = self.names.get(offset..).ok_or(ArchiveError::InvalidName)?;
+                    let end = names.iter().position(|&b| b == b'\n').unwrap_or(names.len());
+                    let name = &names[..end];
+                    name.strip_suffix(b"/").unwrap_or(name)
+                }
+                _ if raw_name.starts_with(b"__.SYMDEF") => continue,
+                _ => raw_name.strip_suffix(b"/").unwrap_or(raw_name),
+            };
+            let name = str::from_utf8(name).map_err(|_| ArchiveError::InvalidName)?;
+            let data = (!self.thin).then_some(data);
+            return Ok(Some(ArchiveMember { name, data }));
+        }
+    }
+}
+
+impl<'a> Iterator for ArchiveMemberIter<'a> {
+    type Item = Result<ArchiveMember<'a>, ArchiveError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.next_member().transpose();
+        if let Some(Err(_)) = result {
+            // The next header can't be found after an invalid one
+            self.offset = self.data.len();
+        }
+        result
+    }
+}
+
+pub struct ArchiveBuildAttrsIter<'a> {
+    members: ArchiveMemberIter<'a>,
+}
+
+impl<'a> Iterator for ArchiveBuildAttrsIter<'a> {
+    type Item = Result<(&'a str, BuildAttrs<'a>), ArchiveError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let member = match self.members.next()? {
+                Ok(member) => member,
+                Err(e) => return Some(Err(e)),
+            };
+            let Some(data) = member.data else {
+                return Some(Err(ArchiveError::ThinMember(member.name.into())));
+            };
+            match BuildAttrs::from_elf32(data) {
+                Ok(build_attrs) => return Some(Ok((member.name, build_attrs))),
+                Err(Elf32Error::NotElf | Elf32Error::NoAttributesSection) => continue,
+                Err(error) => {
+                    return Some(Err(ArchiveError::Member {
+                        name: member.name.into(),
+                        error,
+                    }))
+                }
+            }
+        }
+    }
+}
+
+fn trim_end(bytes: &[u8]) -> &[u8] {
+    let end = bytes.iter().rposition(|&b| b != b' ').map_or(0, |i| i + 1);
+    &bytes[..end]
+}
+
+fn parse_decimal(bytes: &[u8]) -> Option<usize> {
+    let digits = trim_end(bytes);
+    if digits.is_empty() {
+        return None;
+    }
+    digits.iter().try_fold(0usize, |value, &b| {
+        if !b.is_ascii_digit() {
+            return None;
+        }
+        value.checked_mul(10)?.checked_add((b - b'0') as usize)
+    })
+}
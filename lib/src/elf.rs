@@ -0,0 +1,24 @@
+use object::{elf::SHT_ARM_ATTRIBUTES, read::elf::ElfFile32, Endianness, FileKind, Object, ObjectSection, SectionKind};
+
+use crate::{error::ElfError, BuildAttrs, Endian};
+
+impl<'a> BuildAttrs<'a> {
+    /// Reads the build attributes of an ELF32 file in either endianness. The `.ARM.attributes` section is decompressed if
+    /// needed, in which case the returned build attributes own the decompressed data.
+    pub fn from_elf(data: &'a [u8]) -> Result<Self, ElfError> {
+        if FileKind::parse(data).map_err(ElfError::Object)? != FileKind::Elf32 {
+            return Err(ElfError::NotElf32);
+        }
+        let file = ElfFile32::<Endianness>::parse(data).map_err(ElfError::Object)?;
+        let section = file
+            .sections()
+            .find(|s| s.kind() == SectionKind::Elf(SHT_ARM_ATTRIBUTES) && s.name() == Ok(".ARM.attributes"))
+            .ok_or(ElfError::NoAttributesSection)?;
+        let data = section.uncompressed_data().map_err(ElfError::Object)?;
+        let endian = match file.endian() {
+            Endianness::Little => Endian::Little,
+            Endianness::Big => Endian::Big,
+        };
+        Self::from_data(data, endian).map_err(ElfError::BuildAttr)
+    }
+}
@@ -0,0 +1,270 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    error::{Elf32Error, ReadError},
+    read::{read_u16, read_u32, Cursor, Endian},
+    write::patch_u32,
+    BuildAttrs,
+};
+
+pub const EM_ARM: u16 = 40;
+pub const SHT_ARM_ATTRIBUTES: u32 = 0x70000003;
+pub const SHF_COMPRESSED: u32 = 0x800;
+
+const ELF32_HEADER_SIZE: usize = 52;
+const ELFCLASS32: u8 = 1;
+const ELFDATA2LSB: u8 = 1;
+const ELFDATA2MSB: u8 = 2;
+const SHN_XINDEX: u16 = 0xffff;
+const SHT_RELA: u32 = 4;
+const SHT_REL: u32 = 9;
+const PT_NULL: u32 = 0;
+const SECTION_NAME: &[u8] = b".ARM.attributes";
+
+struct SectionHeader {
+    name: u32,
+    kind: u32,
+    flags: u32,
+    offset: u32,
+    size: u32,
+    link: u32,
+    info: u32,
+    addralign: u32,
+}
+
+impl SectionHeader {
+    fn read(data: &[u8], offset: usize, endian: Endian) -> Result<Self, ReadError> {
+        if offset > data.len() {
+            return Err(ReadError::OutOfBounds);
+        }
+        let mut cursor = Cursor::new(data);
+        cursor.set_position(offset);
+        let name = read_u32(&mut cursor, endian)?;
+        let kind = read_u32(&mut cursor, endian)?;
+        let flags = read_u32(&mut cursor, endian)?;
+        let _addr = read_u32(&mut cursor, endian)?;
+        let offset = read_u32(&mut cursor, endian)?;
+        let size = read_u32(&mut cursor, endian)?;
+        let link = read_u32(&mut cursor, endian)?;
+        let info = read_u32(&mut cursor, endian)?;
+        let addralign = read_u32(&mut cursor, endian)?;
+        Ok(Self {
+            name,
+            kind,
+            flags,
+            offset,
+            size,
+            link,
+            info,
+            addralign,
+        })
+    }
+
+    fn data<'a>(&self, data: &'a [u8]) -> Result<&'a [u8], ReadError> {
+        let start = self.offset as usize;
+        let end = start.checked_add(self.size as usize).ok_or(ReadError::OutOfBounds)?;
+        data.get(start..end).ok_or(ReadError::OutOfBounds)
+    }
+}
+
+/// The fields of the ELF header needed to find sections and segments.
+struct FileHeader {
+    endian: Endian,
+    phoff: usize,
+    shoff: usize,
+    phentsize: usize,
+    phnum: usize,
+    shentsize: usize,
+    shnum: usize,
+    shstrndx: usize,
+}
+
+impl FileHeader {
+    fn read(data: &[u8]) -> Result<Self, Elf32Error> {
+        if !data.starts_with(b"\x7fELF") {
+            return Err(Elf32Error::NotElf);
+        }
+        if data.len() < ELF32_HEADER_SIZE {
+            return Err(Elf32Error::Read(ReadError::Eof));
+        }
+        let (class, encoding) = (data[4], data[5]);
+        if class != ELFCLASS32 {
+            return Err(Elf32Error::NotElf32);
+        }
+        let endian = match encoding {
+            ELFDATA2LSB => Endian::Little,
+            ELFDATA2MSB => Endian::Big,
+            _ => return Err(Elf32Error::InvalidEncoding(encoding)),
+        };
+
+        let mut cursor = Cursor::new(data);
+        cursor.set_position(18);
+        let machine = read_u16(&mut cursor, endian).map_err(Elf32Error::Read)?;
+        if machine != EM_ARM {
+            return Err(Elf32Error::NotArm(machine));
+        }
+        cursor.set_position(28);
+        let phoff = read_u32(&mut cursor, endian).map_err(Elf32Error::Read)? as usize;
+        let shoff = read_u32(&mut cursor, endian).map_err(Elf32Error::Read)? as usize;
+        cursor.set_position(42);
+        let phentsize = read_u16(&mut cursor, endian).map_err(Elf32Error::Read)? as usize;
+        let phnum = read_u16(&mut cursor, endian).map_err(Elf32Error::Read)? as usize;
+        let shentsize = read_u16(&mut cursor, endian).map_err(Elf32Error::Read)? as usize;
+        let mut shnum = read_u16(&mut cursor, endian).map_err(Elf32Error::Read)? as usize;
+        let mut shstrndx = read_u16(&mut cursor, endian).map_err(Elf32Error::Read)? as usize;
+
+        if shoff != 0 {
+            // Large section counts and indices are stored in the first section header instead
+            let first = SectionHeader::read(data, shoff, endian).map_err(Elf32Error::Read)?;
+            if shnum == 0 {
+                shnum = first.size as usize;
+            }
+            if shstrndx == SHN_XINDEX as usize {
+                shstrndx = first.link as usize;
+            }
+        }
+        Ok(Self {
+            endian,
+            phoff,
+            shoff,
+            phentsize,
+            phnum,
+            shentsize,
+            shnum,
+            shstrndx,
+        })
+    }
+
+    fn section_offset(&self, index: usize) -> Result<usize, ReadError> {
+        let offset = index
+            .checked_mul(self.shentsize)
+            .and_then(|offset| offset.checked_add(self.shoff));
+        offset.ok_or(ReadError::OutOfBounds)
+    }
+
+    fn section(&self, data: &[u8], index: usize) -> Result<SectionHeader, Elf32Error> {
+        let offset = self.section_offset(index).map_err(Elf32Error::Read)?;
+        SectionHeader::read(data, offset, self.endian).map_err(Elf32Error::Read)
+    }
+
+    /// Returns the index and header of the `.ARM.attributes` section.
+    fn find_attributes_section(&self, data: &[u8]) -> Result<(usize, SectionHeader), Elf32Error> {
+        if self.shoff == 0 {
+            return Err(Elf32Error::NoAttributesSection);
+        }
+        let shstrtab = self.section(data, self.shstrndx)?.data(data).map_err(Elf32Error::Read)?;
+
+        for index in 1..self.shnum {
+            let section = self.section(data, index)?;
+            if section.kind != SHT_ARM_ATTRIBUTES {
+                continue;
+            }
+            let name = shstrtab
+                .get(section.name as usize..)
+                .ok_or(Elf32Error::Read(ReadError::OutOfBounds))?;
+            if !name.starts_with(SECTION_NAME) || name.get(SECTION_NAME.len()) != Some(&0) {
+                continue;
+            }
+            if section.flags & SHF_COMPRESSED != 0 {
+                return Err(Elf32Error::Compressed);
+            }
+            return Ok((index, section));
+        }
+        Err(Elf32Error::NoAttributesSection)
+    }
+}
+
+/// Finds the `.ARM.attributes` section of an ARM ELF32 file by its type and name, and returns its contents and the
+/// endianness of the file. Unlike `BuildAttrs::from_elf`, this has no dependencies but doesn't support compressed sections.
+pub fn find_attributes_section(data: &[u8]) -> Result<(&[u8], Endian), Elf32Error> {
+    let header = FileHeader::read(data)?;
+    let (_, section) = header.find_attributes_section(data)?;
+    Ok((section.data(data).map_err(Elf32Error::Read)?, header.endian))
+}
+
+/// Returns a copy of an ARM ELF32 file with the contents of its `.ARM.attributes` section replaced, for example by the
+/// output of [`BuildAttrsWriter`](crate::write::BuildAttrsWriter). If the new contents are larger, the sections and section
+/// header table placed after the section are moved to make room and their offsets are updated.
+///
+/// Fails if the section has relocations, which would no longer apply to the new contents, or if data which would be moved
+/// is part of a segment.
+pub fn replace_attributes_section(data: &[u8], contents: &[u8]) -> Result<Vec<u8>, Elf32Error> {
+    let header = FileHeader::read(data)?;
+    let endian = header.endian;
+    let (attributes_index, attributes) = header.find_attributes_section(data)?;
+    attributes.data(data).map_err(Elf32Error::Read)?;
+
+    let mut sections = vec![];
+    for index in 0..header.shnum {
+        let section = header.section(data, index)?;
+        if matches!(section.kind, SHT_REL | SHT_RELA) && section.info as usize == attributes_index {
+            return Err(Elf32Error::Relocated);
+        }
+        sections.push(section);
+    }
+
+    let start = attributes.offset as usize;
+    let old_end = start + attributes.size as usize;
+    let shift = if contents.len() <= attributes.size as usize {
+        0
+    } else {
+        let mut cursor = Cursor::new(data);
+        for index in 0..header.phnum {
+            let offset = index
+                .checked_mul(header.phentsize)
+                .and_then(|offset| offset.checked_add(header.phoff))
+                .filter(|offset| *offset <= data.len());
+            cursor.set_position(offset.ok_or(Elf32Error::Read(ReadError::OutOfBounds))?);
+            let kind = read_u32(&mut cursor, endian).map_err(Elf32Error::Read)?;
+            let offset = read_u32(&mut cursor, endian).map_err(Elf32Error::Read)? as usize;
+            // Skip the virtual and physical addresses
+            read_u32(&mut cursor, endian).map_err(Elf32Error::Read)?;
+            read_u32(&mut cursor, endian).map_err(Elf32Error::Read)?;
+            let file_size = read_u32(&mut cursor, endian).map_err(Elf32Error::Read)? as usize;
+            let end = offset
+                .checked_add(file_size)
+                .ok_or(Elf32Error::Read(ReadError::OutOfBounds))?;
+            if kind != PT_NULL && file_size != 0 && end > old_end {
+                return Err(Elf32Error::InSegment);
+            }
+        }
+        // Keep the alignment of everything which is moved
+        let moved = sections.iter().filter(|s| s.offset as usize >= old_end);
+        let mut align = moved.map(|s| s.addralign.max(1) as usize).max().unwrap_or(1);
+        if header.shoff >= old_end || header.phoff >= old_end {
+            align = align.max(4);
+        }
+        (contents.len() - attributes.size as usize).next_multiple_of(align)
+    };
+
+    let mut out = Vec::with_capacity(data.len() + shift);
+    out.extend_from_slice(&data[..start]);
+    out.extend_from_slice(contents);
+    out.resize(old_end + shift, 0);
+    out.extend_from_slice(&data[old_end..]);
+
+    let moved = |offset: usize| if offset >= old_end { offset + shift } else { offset };
+    if header.phnum != 0 {
+        patch_u32(&mut out, 28, moved(header.phoff) as u32, endian);
+    }
+    let shoff = moved(header.shoff);
+    patch_u32(&mut out, 32, shoff as u32, endian);
+    let header = FileHeader { shoff, ..header };
+    for (index, section) in sections.iter().enumerate().skip(1) {
+        let offset = header.section_offset(index).map_err(Elf32Error::Read)?;
+        if index == attributes_index {
+            patch_u32(&mut out, offset + 20, contents.len() as u32, endian);
+        } else {
+            patch_u32(&mut out, offset + 16, moved(section.offset as usize) as u32, endian);
+        }
+    }
+    Ok(out)
+}
+
+impl<'a> BuildAttrs<'a> {
+    /// Reads the build attributes of an ARM ELF32 file using [`find_attributes_section`].
+    pub fn from_elf32(data: &'a [u8]) -> Result<Self, Elf32Error> {
+        let (data, endian) = find_attributes_section(data)?;
+        Self::new(data, endian).map_err(Elf32Error::BuildAttr)
+    }
+}
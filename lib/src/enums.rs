@@ -154,56 +154,118 @@ impl<'a> From<&'a str> for CpuName<'a> {
     }
 }
 
+impl<'a> CpuName<'a> {
+    pub fn as_str(&self) -> &'a str {
+        match self {
+            Self::None => "",
+            Self::Arm7TejS => "ARM7EJ-S",
+            Self::Arm7Tm => "ARM7TM",
+            Self::Arm7Tdm => "ARM7TDM",
+            Self::Arm7Tdmi => "ARM7TDMI",
+            Self::Arm710T => "ARM710T",
+            Self::Arm720T => "ARM720T",
+            Self::Arm740T => "ARM740T",
+            Self::Arm7TmS => "ARM7TM-S",
+            Self::Arm7TdmiS => "ARM7TDMI-S",
+            Self::Arm810 => "ARM810",
+            Self::Arm9Tdmi => "ARM9TDMI",
+            Self::Arm920T => "ARM920T",
+            Self::Arm922T => "ARM922T",
+            Self::Arm940T => "ARM940T",
+            Self::Arm9ES => "ARM9E-S",
+            Self::Arm9EjS => "ARM9EJ-S",
+            Self::Arm926EjS => "ARM926EJ-S",
+            Self::Arm946ES => "ARM946E-S",
+            Self::Arm966ES => "ARM966E-S",
+            Self::Arm968ES => "ARM968E-S",
+            Self::Arm1020E => "ARM1020E",
+            Self::Arm1026EjS => "ARM1026EJ-S",
+            Self::Arm1136JS => "ARM1136J-S",
+            Self::Arm1136JfS => "ARM1136JF-S",
+            Self::Arm1156T2S => "ARM1156T2-S",
+            Self::Arm1156T2FS => "ARM1156T2F-S",
+            Self::Arm1176JzS => "ARM1176JZ-S",
+            Self::Arm1176JzfS => "ARM1176JZF-S",
+            Self::MpCore => "MPCore",
+            Self::CortexM0 => "Cortex-M0",
+            Self::CortexM0Plus => "Cortex-M0plus",
+            Self::CortexM1 => "Cortex-M1",
+            Self::CortexM3 => "Cortex-M3",
+            Self::CortexM4 => "Cortex-M4",
+            Self::Sc000 => "SC000",
+            Self::Sc300 => "SC300",
+            Self::CortexR4 => "Cortex-R4",
+            Self::CortexR4F => "Cortex-R4F",
+            Self::CortexR5 => "Cortex-R5",
+            Self::CortexR7 => "Cortex-R7",
+            Self::CortexA5 => "Cortex-A5",
+            Self::CortexA7 => "Cortex-A7",
+            Self::CortexA8 => "Cortex-A8",
+            Self::CortexA9 => "Cortex-A9",
+            Self::CortexA15 => "Cortex-A16",
+            Self::Other(name) => name,
+        }
+    }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::Arm7TejS => "Arm7TejS",
+            Self::Arm7Tm => "Arm7Tm",
+            Self::Arm7Tdm => "Arm7Tdm",
+            Self::Arm7Tdmi => "Arm7Tdmi",
+            Self::Arm710T => "Arm710T",
+            Self::Arm720T => "Arm720T",
+            Self::Arm740T => "Arm740T",
+            Self::Arm7TmS => "Arm7TmS",
+            Self::Arm7TdmiS => "Arm7TdmiS",
+            Self::Arm810 => "Arm810",
+            Self::Arm9Tdmi => "Arm9Tdmi",
+            Self::Arm920T => "Arm920T",
+            Self::Arm922T => "Arm922T",
+            Self::Arm940T => "Arm940T",
+            Self::Arm9ES => "Arm9ES",
+            Self::Arm9EjS => "Arm9EjS",
+            Self::Arm926EjS => "Arm926EjS",
+            Self::Arm946ES => "Arm946ES",
+            Self::Arm966ES => "Arm966ES",
+            Self::Arm968ES => "Arm968ES",
+            Self::Arm1020E => "Arm1020E",
+            Self::Arm1026EjS => "Arm1026EjS",
+            Self::Arm1136JS => "Arm1136JS",
+            Self::Arm1136JfS => "Arm1136JfS",
+            Self::Arm1156T2S => "Arm1156T2S",
+            Self::Arm1156T2FS => "Arm1156T2FS",
+            Self::Arm1176JzS => "Arm1176JzS",
+            Self::Arm1176JzfS => "Arm1176JzfS",
+            Self::MpCore => "MpCore",
+            Self::CortexM0 => "CortexM0",
+            Self::CortexM0Plus => "CortexM0Plus",
+            Self::CortexM1 => "CortexM1",
+            Self::CortexM3 => "CortexM3",
+            Self::CortexM4 => "CortexM4",
+            Self::Sc000 => "Sc000",
+            Self::Sc300 => "Sc300",
+            Self::CortexR4 => "CortexR4",
+            Self::CortexR4F => "CortexR4F",
+            Self::CortexR5 => "CortexR5",
+            Self::CortexR7 => "CortexR7",
+            Self::CortexA5 => "CortexA5",
+            Self::CortexA7 => "CortexA7",
+            Self::CortexA8 => "CortexA8",
+            Self::CortexA9 => "CortexA9",
+            Self::CortexA15 => "CortexA15",
+            Self::Other(_) => "Other",
+        }
+    }
+}
+
 impl<'a> fmt::Display for CpuName<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::None => Ok(()),
-            Self::Arm7TejS => write!(f, "ARM7EJ-S"),
-            Self::Arm7Tm => write!(f, "ARM7TM"),
-            Self::Arm7Tdm => write!(f, "ARM7TDM"),
-            Self::Arm7Tdmi => write!(f, "ARM7TDMI"),
-            Self::Arm710T => write!(f, "ARM710T"),
-            Self::Arm720T => write!(f, "ARM720T"),
-            Self::Arm740T => write!(f, "ARM740T"),
-            Self::Arm7TmS => write!(f, "ARM7TM-S"),
-            Self::Arm7TdmiS => write!(f, "ARM7TDMI-S"),
-            Self::Arm810 => write!(f, "ARM810"),
-            Self::Arm9Tdmi => write!(f, "ARM9TDMI"),
-            Self::Arm920T => write!(f, "ARM920T"),
-            Self::Arm922T => write!(f, "ARM922T"),
-            Self::Arm940T => write!(f, "ARM940T"),
-            Self::Arm9ES => write!(f, "ARM9E-S"),
-            Self::Arm9EjS => write!(f, "ARM9EJ-S"),
-            Self::Arm926EjS => write!(f, "ARM926EJ-S"),
-            Self::Arm946ES => write!(f, "ARM946E-S"),
-            Self::Arm966ES => write!(f, "ARM966E-S"),
-            Self::Arm968ES => write!(f, "ARM968E-S"),
-            Self::Arm1020E => write!(f, "ARM1020E"),
-            Self::Arm1026EjS => write!(f, "ARM1026EJ-S"),
-            Self::Arm1136JS => write!(f, "ARM1136J-S"),
-            Self::Arm1136JfS => write!(f, "ARM1136JF-S"),
-            Self::Arm1156T2S => write!(f, "ARM1156T2-S"),
-            Self::Arm1156T2FS => write!(f, "ARM1156T2F-S"),
-            Self::Arm1176JzS => write!(f, "ARM1176JZ-S"),
-            Self::Arm1176JzfS => write!(f, "ARM1176JZF-S"),
-            Self::MpCore => write!(f, "MPCore"),
-            Self::CortexM0 => write!(f, "Cortex-M0"),
-            Self::CortexM0Plus => write!(f, "Cortex-M0plus"),
-            Self::CortexM1 => write!(f, "Cortex-M1"),
-            Self::CortexM3 => write!(f, "Cortex-M3"),
-            Self::CortexM4 => write!(f, "Cortex-M4"),
-            Self::Sc000 => write!(f, "SC000"),
-            Self::Sc300 => write!(f, "SC300"),
-            Self::CortexR4 => write!(f, "Cortex-R4"),
-            Self::CortexR4F => write!(f, "Cortex-R4F"),
-            Self::CortexR5 => write!(f, "Cortex-R5"),
-            Self::CortexR7 => write!(f, "Cortex-R7"),
-            Self::CortexA5 => write!(f, "Cortex-A5"),
-            Self::CortexA7 => write!(f, "Cortex-A7"),
-            Self::CortexA8 => write!(f, "Cortex-A8"),
-            Self::CortexA9 => write!(f, "Cortex-A9"),
-            Self::CortexA15 => write!(f, "Cortex-A16"),
             Self::Other(name) => write!(f, "\"{name}\""),
+            _ => write!(f, "{}", self.as_str()),
         }
     }
 }
@@ -257,11 +319,11 @@ pub enum CpuArch {
     V8_1MMainline,
     /// ARMv9A
     V9A,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl CpuArch {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::PreV4 => 0,
             Self::V4 => 1,
@@ -289,10 +351,40 @@ impl CpuArch {
             Self::Unknown(value) => value,
         }
     }
-}
 
-impl From<u8> for CpuArch {
-    fn from(value: u8) -> Self {
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::PreV4 => "PreV4",
+            Self::V4 => "V4",
+            Self::V4T => "V4T",
+            Self::V5T => "V5T",
+            Self::V5TE => "V5TE",
+            Self::V5TEJ => "V5TEJ",
+            Self::V6 => "V6",
+            Self::V6KZ => "V6KZ",
+            Self::V6T2 => "V6T2",
+            Self::V6K => "V6K",
+            Self::V7 => "V7",
+            Self::V6M => "V6M",
+            Self::V6SM => "V6SM",
+            Self::V7EM => "V7EM",
+            Self::V8A => "V8A",
+            Self::V8R => "V8R",
+            Self::V8MBaseline => "V8MBaseline",
+            Self::V8MMainline => "V8MMainline",
+            Self::V8_1A => "V8_1A",
+            Self::V8_2A => "V8_2A",
+            Self::V8_3A => "V8_3A",
+            Self::V8_1MMainline => "V8_1MMainline",
+            Self::V9A => "V9A",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
+}
+
+impl From<u32> for CpuArch {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::PreV4,
             1 => Self::V4,
@@ -366,30 +458,42 @@ pub enum CpuArchProfile {
     Microcontroller,
     /// Application or real-time profile (S)
     Classic,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl CpuArchProfile {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::NotApplicable => 0,
-            Self::Application => b'A',
-            Self::RealTime => b'R',
-            Self::Microcontroller => b'M',
-            Self::Classic => b'S',
+            Self::Application => b'A'.into(),
+            Self::RealTime => b'R'.into(),
+            Self::Microcontroller => b'M'.into(),
+            Self::Classic => b'S'.into(),
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::NotApplicable => "NotApplicable",
+            Self::Application => "Application",
+            Self::RealTime => "RealTime",
+            Self::Microcontroller => "Microcontroller",
+            Self::Classic => "Classic",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for CpuArchProfile {
-    fn from(value: u8) -> Self {
-        match value {
-            0 => Self::NotApplicable,
-            b'A' => Self::Application,
-            b'R' => Self::RealTime,
-            b'M' => Self::Microcontroller,
-            b'S' => Self::Classic,
+impl From<u32> for CpuArchProfile {
+    fn from(value: u32) -> Self {
+        match u8::try_from(value) {
+            Ok(0) => Self::NotApplicable,
+            Ok(b'A') => Self::Application,
+            Ok(b'R') => Self::RealTime,
+            Ok(b'M') => Self::Microcontroller,
+            Ok(b'S') => Self::Classic,
             _ => Self::Unknown(value),
         }
     }
@@ -415,21 +519,30 @@ pub enum ArmIsaUse {
     None,
     /// ARM code is allowed.
     Allowed,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl ArmIsaUse {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::None => 0,
             Self::Allowed => 1,
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::Allowed => "Allowed",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for ArmIsaUse {
-    fn from(value: u8) -> Self {
+impl From<u32> for ArmIsaUse {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::None,
             1 => Self::Allowed,
@@ -463,11 +576,11 @@ pub enum ThumbIsaUse {
     Allowed32Bit,
     /// Thumb code is allowed. The set of permitted instructions can be inferred from CPU arch and CPU arch profile.
     Allowed,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl ThumbIsaUse {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::None => 0,
             Self::Allowed16Bit => 1,
@@ -476,10 +589,21 @@ impl ThumbIsaUse {
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::Allowed16Bit => "Allowed16Bit",
+            Self::Allowed32Bit => "Allowed32Bit",
+            Self::Allowed => "Allowed",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for ThumbIsaUse {
-    fn from(value: u8) -> Self {
+impl From<u32> for ThumbIsaUse {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::None,
             1 => Self::Allowed16Bit,
@@ -523,11 +647,11 @@ pub enum FpArch {
     V8A,
     /// ARMv8-A FP ISA is allowed, but only registers D0-D15 and S0-S31
     V8ALight,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl FpArch {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::None => 0,
             Self::V1 => 1,
@@ -541,10 +665,26 @@ impl FpArch {
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::V1 => "V1",
+            Self::V2 => "V2",
+            Self::V3 => "V3",
+            Self::V3Light => "V3Light",
+            Self::V4 => "V4",
+            Self::V4Light => "V4Light",
+            Self::V8A => "V8A",
+            Self::V8ALight => "V8ALight",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for FpArch {
-    fn from(value: u8) -> Self {
+impl From<u32> for FpArch {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::None,
             1 => Self::V1,
@@ -586,11 +726,11 @@ pub enum WmmxArch {
     V1,
     /// WMMX v2 allowed.
     V2,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl WmmxArch {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::None => 0,
             Self::V1 => 1,
@@ -598,10 +738,20 @@ impl WmmxArch {
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::V1 => "V1",
+            Self::V2 => "V2",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for WmmxArch {
-    fn from(value: u8) -> Self {
+impl From<u32> for WmmxArch {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::None,
             1 => Self::V1,
@@ -635,11 +785,11 @@ pub enum AsimdArch {
     V8A,
     /// ARMv8.1-A Advanced SIMD (Neon) allowed.
     V8_1A,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl AsimdArch {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::None => 0,
             Self::V1 => 1,
@@ -649,10 +799,22 @@ impl AsimdArch {
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::V1 => "V1",
+            Self::V2 => "V2",
+            Self::V8A => "V8A",
+            Self::V8_1A => "V8_1A",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for AsimdArch {
-    fn from(value: u8) -> Self {
+impl From<u32> for AsimdArch {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::None,
             1 => Self::V1,
@@ -686,11 +848,11 @@ pub enum MveArch {
     Int,
     /// Integer and Floating Point M-profile Vector Extension allowed.
     IntFloat,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl MveArch {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::None => 0,
             Self::Int => 1,
@@ -698,10 +860,20 @@ impl MveArch {
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::Int => "Int",
+            Self::IntFloat => "IntFloat",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for MveArch {
-    fn from(value: u8) -> Self {
+impl From<u32> for MveArch {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::None,
             1 => Self::Int,
@@ -733,11 +905,11 @@ pub enum FpHpExt {
     /// Half-precision allowed as an extension to ARMv8.2-A and ASIMD, in addition to those indicated by `FpArch` and
     /// `AsimdArch`.
     ArmV8_2A,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl FpHpExt {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::IfExists => 0,
             Self::VfpV3 => 1,
@@ -745,10 +917,20 @@ impl FpHpExt {
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::IfExists => "IfExists",
+            Self::VfpV3 => "VfpV3",
+            Self::ArmV8_2A => "ArmV8_2A",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for FpHpExt {
-    fn from(value: u8) -> Self {
+impl From<u32> for FpHpExt {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::IfExists,
             1 => Self::VfpV3,
@@ -776,21 +958,30 @@ pub enum CpuUnalignedAccess {
     None,
     /// v6-style unaligned data accesses allowed.
     Allowed,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl CpuUnalignedAccess {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::None => 0,
             Self::Allowed => 1,
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::Allowed => "Allowed",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for CpuUnalignedAccess {
-    fn from(value: u8) -> Self {
+impl From<u32> for CpuUnalignedAccess {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::None,
             1 => Self::Allowed,
@@ -816,21 +1007,30 @@ pub enum T2EeUse {
     None,
     /// The T2EE extension is allowed.
     Allowed,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl T2EeUse {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::None => 0,
             Self::Allowed => 1,
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::Allowed => "Allowed",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for T2EeUse {
-    fn from(value: u8) -> Self {
+impl From<u32> for T2EeUse {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::None,
             1 => Self::Allowed,
@@ -860,11 +1060,11 @@ pub enum VirtualUse {
     VExts,
     /// The TrustZone and virtualization extensions are allowed.
     TrustZoneVExts,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl VirtualUse {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::None => 0,
             Self::TrustZone => 1,
@@ -873,10 +1073,21 @@ impl VirtualUse {
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::TrustZone => "TrustZone",
+            Self::VExts => "VExts",
+            Self::TrustZoneVExts => "TrustZoneVExts",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for VirtualUse {
-    fn from(value: u8) -> Self {
+impl From<u32> for VirtualUse {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::None,
             1 => Self::TrustZone,
@@ -906,21 +1117,30 @@ pub enum MpExtUse {
     None,
     // Use of the ARMv7 MP extension is allowed.
     Allowed,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl MpExtUse {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::None => 0,
             Self::Allowed => 1,
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::Allowed => "Allowed",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for MpExtUse {
-    fn from(value: u8) -> Self {
+impl From<u32> for MpExtUse {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::None,
             1 => Self::Allowed,
@@ -948,11 +1168,11 @@ pub enum DivUse {
     None,
     /// Allowed.
     Allowed,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl DivUse {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::IfExists => 0,
             Self::None => 1,
@@ -960,10 +1180,20 @@ impl DivUse {
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::IfExists => "IfExists",
+            Self::None => "None",
+            Self::Allowed => "Allowed",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for DivUse {
-    fn from(value: u8) -> Self {
+impl From<u32> for DivUse {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::IfExists,
             1 => Self::None,
@@ -991,21 +1221,30 @@ pub enum DspExt {
     IfExists,
     /// DSP instructions are allowed.
     Allowed,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl DspExt {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::IfExists => 0,
             Self::Allowed => 1,
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::IfExists => "IfExists",
+            Self::Allowed => "Allowed",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for DspExt {
-    fn from(value: u8) -> Self {
+impl From<u32> for DspExt {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::IfExists,
             1 => Self::Allowed,
@@ -1033,11 +1272,11 @@ pub enum PacExt {
     OnlyNopSpace,
     /// PAC/AUT instructions are allowed.
     Allowed,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl PacExt {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::None => 0,
             Self::OnlyNopSpace => 1,
@@ -1045,10 +1284,20 @@ impl PacExt {
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::OnlyNopSpace => "OnlyNopSpace",
+            Self::Allowed => "Allowed",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for PacExt {
-    fn from(value: u8) -> Self {
+impl From<u32> for PacExt {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::None,
             1 => Self::OnlyNopSpace,
@@ -1078,11 +1327,11 @@ pub enum BtiExt {
     OnlyNopSpace,
     /// BTI instructions are allowed.
     Allowed,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl BtiExt {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::None => 0,
             Self::OnlyNopSpace => 1,
@@ -1090,10 +1339,20 @@ impl BtiExt {
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::OnlyNopSpace => "OnlyNopSpace",
+            Self::Allowed => "Allowed",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for BtiExt {
-    fn from(value: u8) -> Self {
+impl From<u32> for BtiExt {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::None,
             1 => Self::OnlyNopSpace,
@@ -1129,11 +1388,11 @@ pub enum PcsConfig {
     SymbianOs2004,
     /// Reserved for future Symbian OS configuration.
     SymbianOsFuture,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl PcsConfig {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::None => 0,
             Self::BarePlatform => 1,
@@ -1146,10 +1405,25 @@ impl PcsConfig {
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::BarePlatform => "BarePlatform",
+            Self::LinuxApplication => "LinuxApplication",
+            Self::LinuxDso => "LinuxDso",
+            Self::PalmOs2004 => "PalmOs2004",
+            Self::PalmOsFuture => "PalmOsFuture",
+            Self::SymbianOs2004 => "SymbianOs2004",
+            Self::SymbianOsFuture => "SymbianOsFuture",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for PcsConfig {
-    fn from(value: u8) -> Self {
+impl From<u32> for PcsConfig {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::None,
             1 => Self::BarePlatform,
@@ -1191,11 +1465,11 @@ pub enum AbiPcsR9Use {
     TlsPointer,
     /// R9 not used.
     None,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl AbiPcsR9Use {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::V6 => 0,
             Self::Sb => 1,
@@ -1204,10 +1478,21 @@ impl AbiPcsR9Use {
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::V6 => "V6",
+            Self::Sb => "Sb",
+            Self::TlsPointer => "TlsPointer",
+            Self::None => "None",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for AbiPcsR9Use {
-    fn from(value: u8) -> Self {
+impl From<u32> for AbiPcsR9Use {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::V6,
             1 => Self::Sb,
@@ -1241,11 +1526,11 @@ pub enum AbiPcsRwData {
     SbRel,
     /// Not allowed.
     None,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl AbiPcsRwData {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::Abs => 0,
             Self::PcRel => 1,
@@ -1254,10 +1539,21 @@ impl AbiPcsRwData {
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Abs => "Abs",
+            Self::PcRel => "PcRel",
+            Self::SbRel => "SbRel",
+            Self::None => "None",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for AbiPcsRwData {
-    fn from(value: u8) -> Self {
+impl From<u32> for AbiPcsRwData {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::Abs,
             1 => Self::PcRel,
@@ -1289,11 +1585,11 @@ pub enum AbiPcsRoData {
     PcRel,
     /// Not allowed.
     None,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl AbiPcsRoData {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::Abs => 0,
             Self::PcRel => 1,
@@ -1301,10 +1597,20 @@ impl AbiPcsRoData {
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Abs => "Abs",
+            Self::PcRel => "PcRel",
+            Self::None => "None",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for AbiPcsRoData {
-    fn from(value: u8) -> Self {
+impl From<u32> for AbiPcsRoData {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::Abs,
             1 => Self::PcRel,
@@ -1334,11 +1640,11 @@ pub enum AbiPcsGotUse {
     Direct,
     /// Imported data is allowed to be addressed indirectly.
     Indirect,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl AbiPcsGotUse {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::None => 0,
             Self::Direct => 1,
@@ -1346,10 +1652,20 @@ impl AbiPcsGotUse {
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::Direct => "Direct",
+            Self::Indirect => "Indirect",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for AbiPcsGotUse {
-    fn from(value: u8) -> Self {
+impl From<u32> for AbiPcsGotUse {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::None,
             1 => Self::Direct,
@@ -1379,11 +1695,11 @@ pub enum AbiPcsWcharT {
     Size2,
     /// wchar_T of size 4 allowed.
     Size4,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl AbiPcsWcharT {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::None => 0,
             Self::Size2 => 2,
@@ -1391,10 +1707,20 @@ impl AbiPcsWcharT {
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::Size2 => "Size2",
+            Self::Size4 => "Size4",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for AbiPcsWcharT {
-    fn from(value: u8) -> Self {
+impl From<u32> for AbiPcsWcharT {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::None,
             2 => Self::Size2,
@@ -1426,11 +1752,11 @@ pub enum AbiEnumSize {
     Always32,
     /// Enums visible across an ABI-complying interface are 32-bits.
     Visible32,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl AbiEnumSize {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::None => 0,
             Self::SmallestSize => 1,
@@ -1439,10 +1765,21 @@ impl AbiEnumSize {
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::SmallestSize => "SmallestSize",
+            Self::Always32 => "Always32",
+            Self::Visible32 => "Visible32",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for AbiEnumSize {
-    fn from(value: u8) -> Self {
+impl From<u32> for AbiEnumSize {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::None,
             1 => Self::SmallestSize,
@@ -1478,12 +1815,12 @@ pub enum AbiAlignNeeded {
     Reserved,
     /// Code depends on 8-byte alignment of 8-byte data and alignment of data having up to 2n-byte extended alignment, where n
     /// is in \[4..12\].
-    Align2n(u8),
-    Unknown(u8),
+    Align2n(u32),
+    Unknown(u32),
 }
 
 impl AbiAlignNeeded {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::None => 0,
             Self::Align8 => 1,
@@ -1493,10 +1830,22 @@ impl AbiAlignNeeded {
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::Align8 => "Align8",
+            Self::Align4 => "Align4",
+            Self::Reserved => "Reserved",
+            Self::Align2n(_) => "Align2n",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for AbiAlignNeeded {
-    fn from(value: u8) -> Self {
+impl From<u32> for AbiAlignNeeded {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::None,
             1 => Self::Align8,
@@ -1534,12 +1883,12 @@ pub enum AbiAlignPreserved {
     Reserved,
     /// Code preserves 8-byte alignment of 8-byte data and alignmentt of data having up to 2n-byte extended alignment, where n
     /// is in \[4..12\].
-    Align2n(u8),
-    Unknown(u8),
+    Align2n(u32),
+    Unknown(u32),
 }
 
 impl AbiAlignPreserved {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::None => 0,
             Self::Align8 => 1,
@@ -1549,10 +1898,22 @@ impl AbiAlignPreserved {
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::Align8 => "Align8",
+            Self::Align4 => "Align4",
+            Self::Reserved => "Reserved",
+            Self::Align2n(_) => "Align2n",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for AbiAlignPreserved {
-    fn from(value: u8) -> Self {
+impl From<u32> for AbiAlignPreserved {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::None,
             1 => Self::Align8,
@@ -1584,21 +1945,30 @@ pub enum AbiFpRounding {
     Nearest,
     /// The code decides IEEE-754 rounding modea at run-time.
     RunTime,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl AbiFpRounding {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::Nearest => 0,
             Self::RunTime => 1,
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Nearest => "Nearest",
+            Self::RunTime => "RunTime",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for AbiFpRounding {
-    fn from(value: u8) -> Self {
+impl From<u32> for AbiFpRounding {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::Nearest,
             1 => Self::RunTime,
@@ -1626,11 +1996,11 @@ pub enum AbiFpDenormal {
     Ieee754,
     /// The code depends on the sign being preserved when flushed to zero.
     PreserveSign,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl AbiFpDenormal {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::DontCare => 0,
             Self::Ieee754 => 1,
@@ -1638,10 +2008,20 @@ impl AbiFpDenormal {
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::DontCare => "DontCare",
+            Self::Ieee754 => "Ieee754",
+            Self::PreserveSign => "PreserveSign",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for AbiFpDenormal {
-    fn from(value: u8) -> Self {
+impl From<u32> for AbiFpDenormal {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::DontCare,
             1 => Self::Ieee754,
@@ -1669,21 +2049,30 @@ pub enum AbiFpExceptions {
     None,
     /// The code checks for the IEEE-754 inexact exception.
     CheckInexact,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl AbiFpExceptions {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::None => 0,
             Self::CheckInexact => 1,
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::CheckInexact => "CheckInexact",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for AbiFpExceptions {
-    fn from(value: u8) -> Self {
+impl From<u32> for AbiFpExceptions {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::None,
             1 => Self::CheckInexact,
@@ -1709,21 +2098,30 @@ pub enum AbiFpUserExceptions {
     None,
     /// The code uses IEEE-754 user exceptions.
     Enabled,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl AbiFpUserExceptions {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::None => 0,
             Self::Enabled => 1,
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::Enabled => "Enabled",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for AbiFpUserExceptions {
-    fn from(value: u8) -> Self {
+impl From<u32> for AbiFpUserExceptions {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::None,
             1 => Self::Enabled,
@@ -1753,11 +2151,11 @@ pub enum AbiFpNumberModel {
     InfNaN,
     /// The code allows all float encodings defined by IEEE-754.
     All,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl AbiFpNumberModel {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::None => 0,
             Self::Normal => 1,
@@ -1766,10 +2164,21 @@ impl AbiFpNumberModel {
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::Normal => "Normal",
+            Self::InfNaN => "InfNaN",
+            Self::All => "All",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for AbiFpNumberModel {
-    fn from(value: u8) -> Self {
+impl From<u32> for AbiFpNumberModel {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::None,
             1 => Self::Normal,
@@ -1801,11 +2210,11 @@ pub enum AbiFp16BitFormat {
     Ieee754,
     /// The code uses the VFPv3/ASIMD alternative format for 16-bit floats.
     Alternative,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl AbiFp16BitFormat {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::None => 0,
             Self::Ieee754 => 1,
@@ -1813,10 +2222,20 @@ impl AbiFp16BitFormat {
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::Ieee754 => "Ieee754",
+            Self::Alternative => "Alternative",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for AbiFp16BitFormat {
-    fn from(value: u8) -> Self {
+impl From<u32> for AbiFp16BitFormat {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::None,
             1 => Self::Ieee754,
@@ -1848,11 +2267,11 @@ pub enum AbiHardFpUse {
     Reserved,
     /// Deprecated: Use `Implied` instead.
     ImpliedOld,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl AbiHardFpUse {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::Implied => 0,
             Self::DerivedSingle => 1,
@@ -1861,10 +2280,21 @@ impl AbiHardFpUse {
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Implied => "Implied",
+            Self::DerivedSingle => "DerivedSingle",
+            Self::Reserved => "Reserved",
+            Self::ImpliedOld => "ImpliedOld",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for AbiHardFpUse {
-    fn from(value: u8) -> Self {
+impl From<u32> for AbiHardFpUse {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::Implied,
             1 => Self::DerivedSingle,
@@ -1899,11 +2329,11 @@ pub enum AbiVfpArgs {
     /// Code is compatible with both the base and VFP variants. Non-variadic functions are not allowed to pass float
     /// parameters/results.
     BaseVfp,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl AbiVfpArgs {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::Base => 0,
             Self::Vfp => 1,
@@ -1912,10 +2342,21 @@ impl AbiVfpArgs {
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Base => "Base",
+            Self::Vfp => "Vfp",
+            Self::Toolchain => "Toolchain",
+            Self::BaseVfp => "BaseVfp",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for AbiVfpArgs {
-    fn from(value: u8) -> Self {
+impl From<u32> for AbiVfpArgs {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::Base,
             1 => Self::Vfp,
@@ -1947,11 +2388,11 @@ pub enum AbiWmmxArgs {
     Intel,
     /// WMMX parameter/result passing conforms to toolchain-specific conventions.
     Toolchain,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl AbiWmmxArgs {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::Base => 0,
             Self::Intel => 1,
@@ -1959,10 +2400,20 @@ impl AbiWmmxArgs {
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Base => "Base",
+            Self::Intel => "Intel",
+            Self::Toolchain => "Toolchain",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for AbiWmmxArgs {
-    fn from(value: u8) -> Self {
+impl From<u32> for AbiWmmxArgs {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::Base,
             1 => Self::Intel,
@@ -1992,11 +2443,11 @@ pub enum FramePointerUse {
     WithRecords,
     /// The codes does not create records, but preserves the frame pointer register (FP) value.
     WithoutRecords,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl FramePointerUse {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::None => 0,
             Self::WithRecords => 1,
@@ -2004,10 +2455,20 @@ impl FramePointerUse {
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::WithRecords => "WithRecords",
+            Self::WithoutRecords => "WithoutRecords",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for FramePointerUse {
-    fn from(value: u8) -> Self {
+impl From<u32> for FramePointerUse {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::None,
             1 => Self::WithRecords,
@@ -2035,21 +2496,30 @@ pub enum BtiUse {
     None,
     /// The code is compiled with branch target enforcement.
     Enabled,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl BtiUse {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::None => 0,
             Self::Enabled => 1,
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::Enabled => "Enabled",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for BtiUse {
-    fn from(value: u8) -> Self {
+impl From<u32> for BtiUse {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::None,
             1 => Self::Enabled,
@@ -2075,21 +2545,30 @@ pub enum PacretUse {
     None,
     /// The code is compiled with return address signing and authentication.
     Enabled,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl PacretUse {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::None => 0,
             Self::Enabled => 1,
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::Enabled => "Enabled",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for PacretUse {
-    fn from(value: u8) -> Self {
+impl From<u32> for PacretUse {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::None,
             1 => Self::Enabled,
@@ -2125,11 +2604,11 @@ pub enum AbiOptGoals {
     FavorDebug,
     /// Optimize aggressively for debug experience, but sacrifice speed and small size.
     OptimizeDebug,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl AbiOptGoals {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::None => 0,
             Self::FavorSpeed => 1,
@@ -2141,10 +2620,24 @@ impl AbiOptGoals {
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::FavorSpeed => "FavorSpeed",
+            Self::OptimizeSpeed => "OptimizeSpeed",
+            Self::FavorSize => "FavorSize",
+            Self::OptimizeSize => "OptimizeSize",
+            Self::FavorDebug => "FavorDebug",
+            Self::OptimizeDebug => "OptimizeDebug",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for AbiOptGoals {
-    fn from(value: u8) -> Self {
+impl From<u32> for AbiOptGoals {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::None,
             1 => Self::FavorSpeed,
@@ -2190,11 +2683,11 @@ pub enum AbiFpOptGoals {
     FavorAccuracy,
     /// Optimize aggressively for accuracy, but sacrifice speed and small size.
     OptimizeAccuracy,
-    Unknown(u8),
+    Unknown(u32),
 }
 
 impl AbiFpOptGoals {
-    pub fn value(self) -> u8 {
+    pub fn value(self) -> u32 {
         match self {
             Self::None => 0,
             Self::FavorSpeed => 1,
@@ -2206,10 +2699,24 @@ impl AbiFpOptGoals {
             Self::Unknown(value) => value,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::FavorSpeed => "FavorSpeed",
+            Self::OptimizeSpeed => "OptimizeSpeed",
+            Self::FavorSize => "FavorSize",
+            Self::OptimizeSize => "OptimizeSize",
+            Self::FavorAccuracy => "FavorAccuracy",
+            Self::OptimizeAccuracy => "OptimizeAccuracy",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
-impl From<u8> for AbiFpOptGoals {
-    fn from(value: u8) -> Self {
+impl From<u32> for AbiFpOptGoals {
+    fn from(value: u32) -> Self {
         match value {
             0 => Self::None,
             1 => Self::FavorSpeed,
@@ -2246,11 +2753,11 @@ pub enum Compat<'a> {
     /// This entity conforms to the ABI when built by the given toolchain.
     ByToolchain(&'a str),
     /// This entity does not conform to the ABI, but can be used privately by given flag and vendor name.
-    Private { flag: u8, vendor: &'a str },
+    Private { flag: u32, vendor: &'a str },
 }
 
 impl<'a> Compat<'a> {
-    pub fn new(flag: u8, vendor: &'a str) -> Self {
+    pub fn new(flag: u32, vendor: &'a str) -> Self {
         match flag {
             0 => Self::Always,
             1 => Self::ByToolchain(vendor),
@@ -2258,13 +2765,22 @@ impl<'a> Compat<'a> {
         }
     }
 
-    pub fn value(&self) -> u8 {
+    pub fn value(&self) -> u32 {
         match self {
             Self::Always => 0,
             Self::ByToolchain(_) => 1,
             Self::Private { flag, vendor: _ } => *flag,
         }
     }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Always => "Always",
+            Self::ByToolchain(_) => "ByToolchain",
+            Self::Private { .. } => "Private",
+        }
+    }
 }
 
 impl<'a> fmt::Display for Compat<'a> {
@@ -2278,11 +2794,12 @@ impl<'a> fmt::Display for Compat<'a> {
 }
 
 #[derive(Clone, PartialEq, Eq, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AlsoCompatWith<'a> {
     #[default]
     None,
     Arch(CpuArch),
-    Reserved(Box<Tag<'a>>),
+    Reserved(#[cfg_attr(feature = "serde", serde(borrow))] Box<Tag<'a>>),
 }
 
 impl<'a> AlsoCompatWith<'a> {
@@ -2321,6 +2838,25 @@ impl<'a> From<&'a str> for Conform<'a> {
     }
 }
 
+impl<'a> Conform<'a> {
+    pub fn as_str(&self) -> &'a str {
+        match self {
+            Self::None => "",
+            Self::V2023Q3 => "2023Q3",
+            Self::Unknown(value) => value,
+        }
+    }
+
+    /// Returns the name of the variant, without its fields.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::V2023Q3 => "V2023Q3",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
+}
+
 impl<'a> fmt::Display for Conform<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -1,52 +1,262 @@
-use alloc::string::String;
-use core::str::Utf8Error;
+use alloc::{string::String, vec::Vec};
+use core::{fmt, str::Utf8Error};
 
 use thiserror::Error;
 
+use crate::globals::tag_name;
+
+/// Scope enclosing a tag which failed to be read.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ErrorScope {
+    File,
+    /// Section scope with its section indices
+    Section(Vec<u32>),
+    /// Symbol scope with its symbol values
+    Symbol(Vec<u32>),
+}
+
+impl fmt::Display for ErrorScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::File => write!(f, "file scope"),
+            Self::Section(sections) => write!(f, "section scope {:?}", sections),
+            Self::Symbol(symbols) => write!(f, "symbol scope {:?}", symbols),
+        }
+    }
+}
+
+/// Where in an `.ARM.attributes` section an error occurred.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Location {
+    /// Index of the subsection in the section
+    pub subsection: usize,
+    /// Vendor name of the subsection, or `None` if it could not be read
+    pub vendor: Option<String>,
+    /// Offset of the error from the start of the section
+    pub offset: u32,
+    /// Number of the tag being decoded, if any
+    pub tag: Option<u32>,
+    /// Scope enclosing the tag, if any
+    pub scope: Option<ErrorScope>,
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "offset {:#x} in subsection {}", self.offset, self.subsection)?;
+        if let Some(vendor) = &self.vendor {
+            write!(f, " '{}'", vendor)?;
+        }
+        if let Some(tag) = self.tag {
+            match tag_name(tag) {
+                Some(name) if self.vendor.as_deref() == Some("aeabi") => write!(f, ", tag {} ({})", tag, name)?,
+                _ => write!(f, ", tag {}", tag)?,
+            }
+        }
+        if let Some(scope) = &self.scope {
+            write!(f, ", in {}", scope)?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum BuildAttrError {
     #[error("no data")]
     NoData,
-    #[error("incompatible format version")]
+    #[error("incompatible format version {0:#x}, expected 'A'")]
     IncompatibleVersion(u8),
 }
 
+#[derive(Error, Debug)]
+pub enum Elf32Error {
+    #[error("not an ELF file")]
+    NotElf,
+    #[error("not a 32-bit ELF file")]
+    NotElf32,
+    #[error("invalid ELF data encoding {0}")]
+    InvalidEncoding(u8),
+    #[error("not an ARM ELF file, machine is {0}")]
+    NotArm(u16),
+    #[error("failed to read ELF file")]
+    Read(#[source] ReadError),
+    #[error("compressed .ARM.attributes section is not supported")]
+    Compressed,
+    #[error("no .ARM.attributes section found")]
+    NoAttributesSection,
+    #[error("invalid .ARM.attributes section")]
+    BuildAttr(#[source] BuildAttrError),
+    #[error(".ARM.attributes section has relocations")]
+    Relocated,
+    #[error("resizing .ARM.attributes section would move data of a segment")]
+    InSegment,
+}
+
+#[derive(Error, Debug)]
+pub enum ArchiveError {
+    #[error("not an ar archive")]
+    NotArchive,
+    #[error("invalid archive member header")]
+    InvalidHeader,
+    #[error("invalid archive member name")]
+    InvalidName,
+    #[error("member '{0}' of thin archive is stored outside of the archive")]
+    ThinMember(String),
+    #[error("failed to read archive member '{name}'")]
+    Member {
+        name: String,
+        #[source]
+        error: Elf32Error,
+    },
+}
+
+#[cfg(feature = "elf")]
+#[derive(Error, Debug)]
+pub enum ElfError {
+    // object::Error only implements Error with std, so its message is included instead
+    #[error("failed to parse ELF file: {0}")]
+    Object(object::Error),
+    #[error("not a 32-bit ELF file")]
+    NotElf32,
+    #[error("no .ARM.attributes section found")]
+    NoAttributesSection,
+    #[error("invalid .ARM.attributes section")]
+    BuildAttr(#[source] BuildAttrError),
+}
+
 #[derive(Error, Debug)]
 pub enum ReadError {
-    #[error("UTF8 error")]
-    Utf8(Utf8Error),
-    #[error("no more data (EOF reached)")]
+    #[error("invalid UTF-8 string")]
+    Utf8(#[source] Utf8Error),
+    #[error("unexpected end of data")]
     Eof,
-    #[error("index out of bounds")]
+    #[error("length or offset out of bounds")]
     OutOfBounds,
+    #[error("ULEB128 value overflows integer type")]
+    Overflow,
+}
+
+/// A violation of the structure of the section. With [`ParseOptions::strict`](crate::ParseOptions::strict) it is an
+/// error, otherwise it is collected as a [`ParseWarning`] and parsing goes on.
+#[derive(Error, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Violation {
+    #[error("string or list is missing its null terminator")]
+    MissingTerminator,
+    #[error("trailing bytes after the end of a scope or the last subsection")]
+    TrailingBytes,
+    #[error("scope starts before the previous scope ends")]
+    OverlappingScopes,
+    #[error("subsection has no tags")]
+    EmptySubsection,
+    #[error("length overflows the enclosing scope")]
+    LengthOverflow,
+    #[error("tag or value is cut short by the end of the subsection")]
+    Truncated,
+}
+
+/// A [`Violation`] found while parsing without [`ParseOptions::strict`](crate::ParseOptions::strict).
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParseWarning {
+    pub location: Location,
+    pub violation: Violation,
+}
+
+impl fmt::Display for ParseWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}", self.violation, self.location)
+    }
+}
+
+/// A subsection header which [`SubsectionIter`](crate::SubsectionIter) failed to read.
+#[derive(Error, Debug)]
+pub enum SubsectionError {
+    #[error("failed to read subsection header at {location}")]
+    Read {
+        location: Location,
+        #[source]
+        error: ReadError,
+    },
+    #[error("invalid subsection at {location}")]
+    Invalid {
+        location: Location,
+        #[source]
+        violation: Violation,
+    },
+}
+
+impl SubsectionError {
+    /// Returns where in the section the error occurred.
+    pub fn location(&self) -> &Location {
+        match self {
+            Self::Read { location, error: _ } | Self::Invalid { location, violation: _ } => location,
+        }
+    }
 }
 
 #[derive(Error, Debug)]
 pub enum PublicAttrsError {
-    #[error("invalid subsection name, should be 'aeabi'")]
-    InvalidName(String),
-    #[error("tag error")]
-    Tag(TagError),
-    #[error("no tags")]
-    NoTags,
-    #[error("expected first tag to be a file tag")]
-    NoFileTag,
-    #[error("duplicate file tag")]
-    DuplicateFileTag,
-    #[error("expected to be in file scope")]
-    NotFileScope,
-    #[error("expected to be in section scope")]
-    NotSectionScope,
-    #[error("enclosed scope ends before parent scope")]
-    ScopeEndsBeforeParent,
+    #[error("invalid subsection name at {location}, should be 'aeabi'")]
+    InvalidName { location: Location },
+    #[error("failed to read tag at {location}")]
+    Tag {
+        location: Location,
+        #[source]
+        error: TagError,
+    },
+    #[error("no tags at {location}")]
+    NoTags { location: Location },
+    #[error("expected first tag to be a file tag at {location}")]
+    NoFileTag { location: Location },
+    #[error("duplicate file tag at {location}")]
+    DuplicateFileTag { location: Location },
+    #[error("expected to be in file scope at {location}")]
+    NotFileScope { location: Location },
+    #[error("expected to be in section scope at {location}")]
+    NotSectionScope { location: Location },
+    #[error("scope does not end within its parent scope at {location}")]
+    ScopeEndsBeforeParent { location: Location },
+    #[error("invalid tag structure at {location}")]
+    Invalid {
+        location: Location,
+        #[source]
+        violation: Violation,
+    },
+}
+
+impl PublicAttrsError {
+    /// Returns where in the section the error occurred.
+    pub fn location(&self) -> &Location {
+        match self {
+            Self::InvalidName { location }
+            | Self::Tag { location, error: _ }
+            | Self::NoTags { location }
+            | Self::NoFileTag { location }
+            | Self::DuplicateFileTag { location }
+            | Self::NotFileScope { location }
+            | Self::NotSectionScope { location }
+            | Self::ScopeEndsBeforeParent { location }
+            | Self::Invalid { location, violation: _ } => location,
+        }
+    }
+}
+
+/// A tag which [`TryPublicTagIter`](crate::TryPublicTagIter) failed to read.
+#[derive(Error, Debug)]
+#[error("failed to read tag at {location}")]
+pub struct TagIterError {
+    /// Location of the byte which could not be read, or the end of the subsection if it ended too early
+    pub location: Location,
+    /// Offset of the start of the tag from the start of the section
+    pub start: u32,
+    #[source]
+    pub error: TagError,
 }
 
 #[derive(Error, Debug)]
 pub enum TagError {
-    #[error("incompatible tag value")]
-    IncompatibleTagValue(u8),
-    #[error("read error")]
-    Read(ReadError),
+    #[error("tag {0} is unknown and can't be skipped, as it is below 32")]
+    IncompatibleTagValue(u32),
+    #[error("failed to read tag value")]
+    Read(#[source] ReadError),
     #[error("invalid scope tag")]
     InvalidScopeTag,
     #[error("expected null")]
@@ -63,3 +63,75 @@ pub const Tag_MPextension_use_OLD: u8 = 70;
 pub const Tag_FramePointer_use: u8 = 72;
 pub const Tag_BTI_use: u8 = 74;
 pub const Tag_PACRET_use: u8 = 76;
+
+/// Returns the name of a public tag, or `None` if it is unknown. Tags with several names are given their current name, such
+/// as Tag_FP_arch rather than Tag_VFP_arch.
+pub fn tag_name(tag: u32) -> Option<&'static str> {
+    let name = match u8::try_from(tag).ok()? {
+        Tag_File => "Tag_File",
+        Tag_Section => "Tag_Section",
+        Tag_Symbol => "Tag_Symbol",
+        Tag_CPU_raw_name => "Tag_CPU_raw_name",
+        Tag_CPU_name => "Tag_CPU_name",
+        Tag_CPU_arch => "Tag_CPU_arch",
+        Tag_CPU_arch_profile => "Tag_CPU_arch_profile",
+        Tag_ARM_ISA_use => "Tag_ARM_ISA_use",
+        Tag_THUMB_ISA_use => "Tag_THUMB_ISA_use",
+        Tag_FP_arch => "Tag_FP_arch",
+        Tag_WMMX_arch => "Tag_WMMX_arch",
+        Tag_Advanced_SIMD_arch => "Tag_Advanced_SIMD_arch",
+        Tag_PCS_config => "Tag_PCS_config",
+        Tag_ABI_PCS_R9_use => "Tag_ABI_PCS_R9_use",
+        Tag_ABI_PCS_RW_data => "Tag_ABI_PCS_RW_data",
+        Tag_ABI_PCS_RO_data => "Tag_ABI_PCS_RO_data",
+        Tag_ABI_PCS_GOT_use => "Tag_ABI_PCS_GOT_use",
+        Tag_ABI_PCS_wchar_t => "Tag_ABI_PCS_wchar_t",
+        Tag_ABI_FP_rounding => "Tag_ABI_FP_rounding",
+        Tag_ABI_FP_denormal => "Tag_ABI_FP_denormal",
+        Tag_ABI_FP_exceptions => "Tag_ABI_FP_exceptions",
+        Tag_ABI_FP_user_exceptions => "Tag_ABI_FP_user_exceptions",
+        Tag_ABI_FP_number_model => "Tag_ABI_FP_number_model",
+        Tag_ABI_align_needed => "Tag_ABI_align_needed",
+        Tag_ABI_align_preserved => "Tag_ABI_align_preserved",
+        Tag_ABI_enum_size => "Tag_ABI_enum_size",
+        Tag_ABI_HardFP_use => "Tag_ABI_HardFP_use",
+        Tag_ABI_VFP_args => "Tag_ABI_VFP_args",
+        Tag_ABI_WMMX_args => "Tag_ABI_WMMX_args",
+        Tag_ABI_optimization_goals => "Tag_ABI_optimization_goals",
+        Tag_ABI_FP_optimization_goals => "Tag_ABI_FP_optimization_goals",
+        Tag_compatibility => "Tag_compatibility",
+        Tag_CPU_unaligned_access => "Tag_CPU_unaligned_access",
+        Tag_FP_HP_extension => "Tag_FP_HP_extension",
+        Tag_ABI_FP_16bit_format => "Tag_ABI_FP_16bit_format",
+        Tag_MPextension_use => "Tag_MPextension_use",
+        Tag_DIV_use => "Tag_DIV_use",
+        Tag_DSP_extension => "Tag_DSP_extension",
+        Tag_MVE_arch => "Tag_MVE_arch",
+        Tag_PAC_extension => "Tag_PAC_extension",
+        Tag_BTI_extension => "Tag_BTI_extension",
+        Tag_nodefaults => "Tag_nodefaults",
+        Tag_also_compatible_with => "Tag_also_compatible_with",
+        Tag_T2EE_use => "Tag_T2EE_use",
+        Tag_conformance => "Tag_conformance",
+        Tag_Virtualization_use => "Tag_Virtualization_use",
+        Tag_MPextension_use_OLD => "Tag_MPextension_use_OLD",
+        Tag_FramePointer_use => "Tag_FramePointer_use",
+        Tag_BTI_use => "Tag_BTI_use",
+        Tag_PACRET_use => "Tag_PACRET_use",
+        _ => return None,
+    };
+    Some(name)
+}
+
+/// Returns the number of a public tag from its name, such as `Tag_ABI_VFP_args`, or `None` if it is unknown. Deprecated
+/// names like `Tag_VFP_arch` are accepted.
+pub fn tag_from_name(name: &str) -> Option<u32> {
+    let tag = match name {
+        "Tag_VFP_arch" => Tag_VFP_arch,
+        "Tag_ABI_align8_needed" => Tag_ABI_align8_needed,
+        "Tag_ABI_align8_preserved" => Tag_ABI_align8_preserved,
+        "Tag_VFP_HP_extension" => Tag_VFP_HP_extension,
+        _ => return (0..128).find(|&tag| tag_name(tag) == Some(name)),
+    };
+    Some(tag.into())
+}
@@ -1,29 +1,56 @@
 #![no_std]
 extern crate alloc;
 
+pub mod archive;
+#[cfg(feature = "elf")]
+mod elf;
+pub mod elf32;
 pub mod enums;
 pub mod error;
 pub mod globals;
+pub mod lint;
+pub mod merge;
 pub mod read;
+#[cfg(feature = "serde")]
+mod serde_impls;
 pub mod tag;
+pub mod vendor;
+pub mod write;
 
-use alloc::{collections::BTreeMap, string::ToString};
+use alloc::{borrow::Cow, collections::BTreeMap, format, string::ToString, vec::Vec};
 use core::fmt;
 
 use enums::*;
-use error::{BuildAttrError, PublicAttrsError, ReadError, TagError};
-use read::{read_string, read_u32, Cursor};
-use tag::Tag;
+use error::{
+    BuildAttrError, ErrorScope, Location, ParseWarning, PublicAttrsError, ReadError, SubsectionError, TagError, TagIterError,
+    Violation,
+};
+use globals::{Tag_File, Tag_Section, Tag_Symbol};
+use read::{read_string, read_u32, read_uleb128, Cursor};
+use tag::{Tag, UnknownValue};
+use vendor::{VendorParser, VendorTagIter};
 
 pub use read::Endian;
 
+/// Options for parsing build attributes.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct ParseOptions {
+    /// Fail on any [`Violation`] of the structure of the section. Otherwise, violations are collected as warnings and
+    /// parsing goes on.
+    pub strict: bool,
+}
+
 pub struct BuildAttrs<'a> {
-    data: &'a [u8],
+    data: Cow<'a, [u8]>,
     endian: Endian,
 }
 
 impl<'a> BuildAttrs<'a> {
     pub fn new(data: &'a [u8], endian: Endian) -> Result<Self, BuildAttrError> {
+        Self::from_data(Cow::Borrowed(data), endian)
+    }
+
+    fn from_data(data: Cow<'a, [u8]>, endian: Endian) -> Result<Self, BuildAttrError> {
         if data.is_empty() {
             Err(BuildAttrError::NoData)
         } else {
@@ -41,11 +68,29 @@ impl<'a> BuildAttrs<'a> {
         self.data[0]
     }
 
-    pub fn subsections(&self) -> SubsectionIter {
+    pub fn endian(&self) -> Endian {
+        self.endian
+    }
+
+    /// Returns the contents of the whole section, starting with the format version.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub fn subsections(&self) -> SubsectionIter<'_> {
+        self.subsections_with_options(ParseOptions::default())
+    }
+
+    /// Returns an iterator over the subsections, whose tags are also parsed with `options`.
+    pub fn subsections_with_options(&self, options: ParseOptions) -> SubsectionIter<'_> {
         let data = &self.data[1..];
         SubsectionIter {
             cursor: Cursor::new(data),
             endian: self.endian,
+            index: 0,
+            options,
+            warnings: Vec::new(),
+            done: false,
         }
     }
 }
@@ -53,36 +98,115 @@ impl<'a> BuildAttrs<'a> {
 pub struct SubsectionIter<'a> {
     cursor: Cursor<'a>,
     endian: Endian,
+    index: usize,
+    options: ParseOptions,
+    warnings: Vec<ParseWarning>,
+    done: bool,
+}
+
+impl<'a> SubsectionIter<'a> {
+    /// Returns the offset in the section of the next subsection, or after a subsection failed to be read, the offset
+    /// where reading stopped.
+    pub fn offset(&self) -> u32 {
+        // Skip the format version
+        self.cursor.position() as u32 + 1
+    }
+
+    /// Returns the violations found so far, if not parsing strictly.
+    pub fn warnings(&self) -> &[ParseWarning] {
+        &self.warnings
+    }
+
+    fn location(&self, vendor_name: Option<&str>, offset: u32) -> Location {
+        Location {
+            subsection: self.index,
+            vendor: vendor_name.map(ToString::to_string),
+            offset,
+            tag: None,
+            scope: None,
+        }
+    }
+
+    /// Ends the iteration at a subsection which failed to be read, as the position of the next one is not known.
+    fn error(&mut self, vendor_name: Option<&str>, offset: u32, error: ReadError) -> SubsectionError {
+        self.done = true;
+        let location = self.location(vendor_name, offset);
+        SubsectionError::Read { location, error }
+    }
+
+    /// Fails with the violation in strict mode, otherwise collects it as a warning.
+    fn violation(&mut self, vendor_name: Option<&str>, offset: u32, violation: Violation) -> Result<(), SubsectionError> {
+        let location = self.location(vendor_name, offset);
+        if self.options.strict {
+            Err(SubsectionError::Invalid { location, violation })
+        } else {
+            self.warnings.push(ParseWarning { location, violation });
+            Ok(())
+        }
+    }
+
+    /// Ends the iteration at a subsection header which was cut short by the end of the section.
+    fn truncated(&mut self, start: u32) -> Option<Result<Subsection<'a>, SubsectionError>> {
+        self.done = true;
+        if start as usize > self.cursor.get_ref().len() {
+            return None;
+        }
+        self.violation(None, start, Violation::TrailingBytes).err().map(Err)
+    }
 }
 
 impl<'a> Iterator for SubsectionIter<'a> {
-    type Item = Result<Subsection<'a>, ReadError>;
+    type Item = Result<Subsection<'a>, SubsectionError>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let start = self.offset();
         let length = match read_u32(&mut self.cursor, self.endian) {
             Ok(length) => length,
-            Err(ReadError::Eof) => return None,
-            Err(e) => return Some(Err(e)),
+            Err(ReadError::Eof) => return self.truncated(start),
+            Err(e) => return Some(Err(self.error(None, self.offset(), e))),
         };
         let vendor_name = match read_string(&mut self.cursor) {
             Ok(vendor_name) => vendor_name,
-            Err(ReadError::Eof) => return None,
-            Err(e) => return Some(Err(e)),
+            Err(ReadError::Eof) => return self.truncated(start),
+            Err(e) => return Some(Err(self.error(None, self.offset(), e))),
         };
+        if self.cursor.missing_null {
+            self.cursor.missing_null = false;
+            if let Err(e) = self.violation(Some(vendor_name), start + 4, Violation::MissingTerminator) {
+                return Some(Err(e));
+            }
+        }
         let name_size = vendor_name.len() + 1;
 
         let pos = self.cursor.position();
-        let end = pos + length as usize - name_size - 4;
-        self.cursor.set_position(end);
         let data = self.cursor.get_ref();
+        let Some(end) = (length as usize).checked_sub(name_size + 4).map(|size| pos + size) else {
+            return Some(Err(self.error(Some(vendor_name), start, ReadError::OutOfBounds)));
+        };
         if end > data.len() {
-            return Some(Err(ReadError::OutOfBounds));
+            return Some(Err(self.error(Some(vendor_name), start, ReadError::OutOfBounds)));
         }
+        self.cursor.set_position(end);
         let data = &data[pos..end];
+        if data.is_empty() {
+            if let Err(e) = self.violation(Some(vendor_name), start, Violation::EmptySubsection) {
+                self.index += 1;
+                return Some(Err(e));
+            }
+        }
+        let index = self.index;
+        self.index += 1;
         Some(Ok(Subsection {
             data,
             endian: self.endian,
             vendor_name,
+            index,
+            // Skip the format version
+            offset: pos as u32 + 1,
+            options: self.options,
         }))
     }
 }
@@ -91,6 +215,9 @@ pub struct Subsection<'a> {
     data: &'a [u8],
     endian: Endian,
     vendor_name: &'a str,
+    index: usize,
+    offset: u32,
+    options: ParseOptions,
 }
 
 impl<'a> Subsection<'a> {
@@ -109,6 +236,42 @@ impl<'a> Subsection<'a> {
     pub fn vendor_name(&self) -> &str {
         self.vendor_name
     }
+
+    /// Returns the index of the subsection in the section.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the offset of the subsection data, which follows the vendor name, from the start of the section.
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+
+    /// Returns the location of `offset` in the subsection data.
+    fn location(&self, offset: u32, tag: Option<u32>, scope: Option<ErrorScope>) -> Location {
+        Location {
+            subsection: self.index,
+            vendor: Some(self.vendor_name.to_string()),
+            offset: self.offset + offset,
+            tag,
+            scope,
+        }
+    }
+
+    /// Fails with the violation in strict mode, otherwise collects it as a warning.
+    fn violation(
+        &self,
+        warnings: &mut Vec<ParseWarning>,
+        location: Location,
+        violation: Violation,
+    ) -> Result<(), PublicAttrsError> {
+        if self.options.strict {
+            Err(PublicAttrsError::Invalid { location, violation })
+        } else {
+            warnings.push(ParseWarning { location, violation });
+            Ok(())
+        }
+    }
 }
 
 impl<'a> Subsection<'a> {
@@ -119,145 +282,196 @@ impl<'a> Subsection<'a> {
                 endian: self.endian,
             })
         } else {
-            Err(PublicAttrsError::InvalidName(self.vendor_name.to_string()))
+            Err(PublicAttrsError::InvalidName {
+                location: self.location(0, None, None),
+            })
+        }
+    }
+
+    /// Like [`Subsection::into_public_tag_iter`], but the iterator yields an error for a tag which fails to be read, instead
+    /// of stopping silently. See [`TryPublicTagIter::with_recovery`] to read the tags which follow.
+    pub fn into_try_public_tag_iter(self) -> Result<TryPublicTagIter<'a>, PublicAttrsError> {
+        if self.is_aeabi() {
+            Ok(TryPublicTagIter {
+                cursor: Cursor::new(self.data),
+                endian: self.endian,
+                recover: false,
+                scopes: Vec::new(),
+                done: false,
+                location: self.location(0, None, None),
+            })
+        } else {
+            Err(PublicAttrsError::InvalidName {
+                location: self.location(0, None, None),
+            })
+        }
+    }
+
+    /// Returns an iterator over the tags of a vendor subsection, decoded by `parser`. Use [`vendor::default_parser`] to get a
+    /// built-in parser for the vendor name.
+    pub fn into_vendor_tag_iter<'p>(self, parser: &'p dyn VendorParser) -> VendorTagIter<'a, 'p> {
+        VendorTagIter {
+            cursor: Cursor::new(self.data),
+            endian: self.endian,
+            parser,
         }
     }
 
     pub fn into_public_attributes(self) -> Result<File<'a>, PublicAttrsError> {
+        self.into_public_attributes_with_warnings().map(|(file, _)| file)
+    }
+
+    /// Like [`Self::into_public_attributes`], but also returns the violations found, unless parsing strictly. Strict
+    /// parsing is enabled by [`BuildAttrs::subsections_with_options`].
+    pub fn into_public_attributes_with_warnings(self) -> Result<(File<'a>, Vec<ParseWarning>), PublicAttrsError> {
         let data_len = self.data.len();
+        let mut warnings = Vec::new();
 
         let mut cursor = Cursor::new(self.data);
         let first_tag = match Tag::read(&mut cursor, self.endian) {
             Ok(tag) => tag,
-            Err(TagError::Read(ReadError::Eof)) => return Err(PublicAttrsError::NoTags),
-            Err(e) => return Err(PublicAttrsError::Tag(e)),
+            Err(TagError::Read(ReadError::Eof)) => {
+                return Err(PublicAttrsError::NoTags {
+                    location: self.location(0, None, None),
+                })
+            }
+            Err(error) => {
+                let location = self.location(cursor.position() as u32, peek_tag(self.data, 0), None);
+                return Err(PublicAttrsError::Tag { location, error });
+            }
         };
 
         if let Tag::File { end_offset } = first_tag {
             if end_offset as usize != data_len {
-                return Err(PublicAttrsError::ScopeEndsBeforeParent);
+                return Err(PublicAttrsError::ScopeEndsBeforeParent {
+                    location: self.location(0, Some(Tag_File.into()), None),
+                });
             }
         } else {
-            return Err(PublicAttrsError::NoFileTag);
+            return Err(PublicAttrsError::NoFileTag {
+                location: self.location(0, Some(first_tag.raw_tag()), None),
+            });
         }
 
         let mut file = File::default();
         let mut attrs = &mut file.attributes;
-        let mut curr_section = None;
-        let mut curr_symbol = None;
+        let mut tags = &mut file.tags;
+        let mut curr_section: Option<(u32, Vec<u32>)> = None;
+        let mut curr_symbol: Option<(u32, Vec<u32>)> = None;
 
         loop {
             let offset = cursor.position() as u32;
             let tag = match Tag::read(&mut cursor, self.endian) {
                 Ok(tag) => tag,
-                Err(TagError::Read(ReadError::Eof)) => break,
-                Err(e) => return Err(PublicAttrsError::Tag(e)),
+                Err(TagError::Read(ReadError::Eof)) if offset as usize == data_len => break,
+                Err(TagError::Read(ReadError::Eof)) => {
+                    // A tag cut short by the end of the subsection
+                    let tag = peek_tag(self.data, offset);
+                    let scope = scope_at(offset, &curr_section, &curr_symbol);
+                    let location = self.location(offset, tag, Some(scope));
+                    let violation = if tag == Some(Tag_Section.into()) || tag == Some(Tag_Symbol.into()) {
+                        Violation::MissingTerminator
+                    } else {
+                        Violation::Truncated
+                    };
+                    self.violation(&mut warnings, location, violation)?;
+                    break;
+                }
+                Err(error) => {
+                    let scope = scope_at(offset, &curr_section, &curr_symbol);
+                    let location = self.location(cursor.position() as u32, peek_tag(self.data, offset), Some(scope));
+                    return Err(PublicAttrsError::Tag { location, error });
+                }
             };
+            let raw_tag = tag.raw_tag();
+            let location = |scope| self.location(offset, Some(raw_tag), Some(scope));
 
-            if let Some((end_offset, _)) = curr_symbol {
-                if offset >= end_offset {
+            if cursor.missing_null {
+                cursor.missing_null = false;
+                let location = location(scope_at(offset, &curr_section, &curr_symbol));
+                self.violation(&mut warnings, location, Violation::MissingTerminator)?;
+            }
+
+            if let Some((end_offset, _)) = &curr_symbol {
+                if offset >= *end_offset {
                     curr_symbol = None;
-                    attrs = if let Some((_, sections)) = curr_section {
-                        &mut file.sections.entry(sections).or_default().attributes
+                    (attrs, tags) = if let Some((_, sections)) = &curr_section {
+                        let section = file.sections.entry(sections.clone()).or_default();
+                        (&mut section.attributes, &mut section.tags)
                     } else {
-                        &mut file.attributes
+                        (&mut file.attributes, &mut file.tags)
                     };
                 }
             }
 
-            if let Some((end_offset, _)) = curr_section {
-                if offset >= end_offset {
+            if let Some((end_offset, _)) = &curr_section {
+                if offset >= *end_offset {
                     curr_section = None;
-                    attrs = &mut file.attributes;
+                    (attrs, tags) = (&mut file.attributes, &mut file.tags);
                 }
             }
 
             match tag {
-                Tag::File { end_offset: _ } => return Err(PublicAttrsError::DuplicateFileTag),
+                Tag::File { end_offset: _ } => {
+                    let location = location(scope_at(offset, &curr_section, &curr_symbol));
+                    return Err(PublicAttrsError::DuplicateFileTag { location });
+                }
                 Tag::Section { end_offset, sections } => {
+                    if end_offset as usize > data_len {
+                        let location = location(ErrorScope::File);
+                        self.violation(&mut warnings, location, Violation::LengthOverflow)?;
+                    }
                     if curr_section.is_none() && curr_symbol.is_none() {
-                        let section = file.sections.entry(sections).or_default();
-                        attrs = &mut section.attributes;
+                        let section = file.sections.entry(sections.clone()).or_default();
+                        (attrs, tags) = (&mut section.attributes, &mut section.tags);
                         curr_section = Some((end_offset, sections));
                     } else {
-                        return Err(PublicAttrsError::NotFileScope);
+                        let location = location(scope_at(offset, &curr_section, &curr_symbol));
+                        return Err(PublicAttrsError::NotFileScope { location });
                     }
                 }
                 Tag::Symbol { end_offset, symbols } => {
                     if let Some((section_end, sections)) = &curr_section {
                         if end_offset > *section_end {
-                            return Err(PublicAttrsError::ScopeEndsBeforeParent);
+                            let location = location(ErrorScope::Section(sections.clone()));
+                            return Err(PublicAttrsError::ScopeEndsBeforeParent { location });
+                        }
+                        if let Some((_, symbols)) = &curr_symbol {
+                            let location = location(ErrorScope::Symbol(symbols.clone()));
+                            self.violation(&mut warnings, location, Violation::OverlappingScopes)?;
                         }
-                        let symbol = file.sections.entry(sections).or_default().symbols.entry(symbols).or_default();
-                        attrs = &mut symbol.attributes;
+                        let section = file.sections.entry(sections.clone()).or_default();
+                        let symbol = section.symbols.entry(symbols.clone()).or_default();
+                        (attrs, tags) = (&mut symbol.attributes, &mut symbol.tags);
                         curr_symbol = Some((end_offset, symbols));
                     } else {
-                        return Err(PublicAttrsError::NotSectionScope);
+                        let location = location(ErrorScope::File);
+                        return Err(PublicAttrsError::NotSectionScope { location });
                     }
                 }
-                Tag::CpuRawName(x) => attrs.cpu_raw_name = Some(x),
-                Tag::CpuName(x) => attrs.cpu_name = Some(x),
-                Tag::CpuArch(x) => attrs.cpu_arch = Some(x),
-                Tag::CpuArchProfile(x) => attrs.cpu_arch_profile = Some(x),
-                Tag::ArmIsaUse(x) => attrs.arm_isa_use = Some(x),
-                Tag::ThumbIsaUse(x) => attrs.thumb_isa_use = Some(x),
-                Tag::FpArch(x) => attrs.fp_arch = Some(x),
-                Tag::WmmxArch(x) => attrs.wmmx_arch = Some(x),
-                Tag::AsimdArch(x) => attrs.asimd_arch = Some(x),
-                Tag::PcsConfig(x) => attrs.pcs_config = Some(x),
-                Tag::AbiPcsR9Use(x) => attrs.abi_pcs_r9_use = Some(x),
-                Tag::AbiPcsRwData(x) => attrs.abi_pcs_rw_data = Some(x),
-                Tag::AbiPcsRoData(x) => attrs.abi_pcs_ro_data = Some(x),
-                Tag::AbiPcsGotUse(x) => attrs.abi_pcs_got_use = Some(x),
-                Tag::AbiPcsWcharT(x) => attrs.abi_pcs_wchar_t = Some(x),
-                Tag::AbiFpRounding(x) => attrs.abi_fp_rounding = Some(x),
-                Tag::AbiFpDenormal(x) => attrs.abi_fp_denormal = Some(x),
-                Tag::AbiFpExceptions(x) => attrs.abi_fp_exceptions = Some(x),
-                Tag::AbiFpUserExceptions(x) => attrs.abi_fp_user_exceptions = Some(x),
-                Tag::AbiFpNumberModel(x) => attrs.abi_fp_number_model = Some(x),
-                Tag::AbiAlignNeeded(x) => attrs.abi_align_needed = Some(x),
-                Tag::AbiAlignPreserved(x) => attrs.abi_align_preserved = Some(x),
-                Tag::AbiEnumSize(x) => attrs.abi_enum_size = Some(x),
-                Tag::AbiHardFpUse(x) => attrs.abi_hardfp_use = Some(x),
-                Tag::AbiVfpArgs(x) => attrs.abi_vfp_args = Some(x),
-                Tag::AbiWmmxArgs(x) => attrs.abi_wmmx_args = Some(x),
-                Tag::AbiOptGoals(x) => attrs.abi_opt_goals = Some(x),
-                Tag::AbiFpOptGoals(x) => attrs.abi_fp_opt_goals = Some(x),
-                Tag::Compat(x) => attrs.compat = Some(x),
-                Tag::CpuUnalignedAccess(x) => attrs.cpu_unaligned_access = Some(x),
-                Tag::FpHpExt(x) => attrs.fp_hp_ext = Some(x),
-                Tag::AbiFp16BitFormat(x) => attrs.abi_fp_16bit_format = Some(x),
-                Tag::MpExtUse(x) => attrs.mp_ext_use = Some(x),
-                Tag::DivUse(x) => attrs.div_use = Some(x),
-                Tag::DspExt(x) => attrs.dsp_ext = Some(x),
-                Tag::MveArch(x) => attrs.mve_arch = Some(x),
-                Tag::PacExt(x) => attrs.pac_ext = Some(x),
-                Tag::BtiExt(x) => attrs.bti_ext = Some(x),
-                Tag::AlsoCompatWith(x) => attrs.also_compat_with = Some(x),
-                Tag::Conform(x) => attrs.conform = Some(x),
-                Tag::T2EeUse(x) => attrs.t2ee_use = Some(x),
-                Tag::VirtualUse(x) => attrs.virtual_use = Some(x),
-                Tag::FramePointerUse(x) => attrs.frame_pointer_use = Some(x),
-                Tag::BtiUse(x) => attrs.bti_use = Some(x),
-                Tag::PacretUse(x) => attrs.pacret_use = Some(x),
-                Tag::NoDefaults => attrs.no_defaults = true,
+                tag => {
+                    tags.push((offset, tag.clone()));
+                    attrs.set(tag)
+                }
             }
         }
 
         for section in file.sections.values_mut() {
             if !file.attributes.no_defaults && section.attributes.empty() {
                 section.attributes.inherit(&file.attributes);
+                section.inherited = true;
             }
             if !section.attributes.no_defaults {
                 for symbol in section.symbols.values_mut() {
                     if symbol.attributes.empty() {
                         symbol.attributes.inherit(&section.attributes);
+                        symbol.inherited = true;
                     }
                 }
             }
         }
 
-        Ok(file)
+        Ok((file, warnings))
     }
 }
 
@@ -266,6 +480,13 @@ pub struct PublicTagIter<'a> {
     endian: Endian,
 }
 
+impl<'a> PublicTagIter<'a> {
+    /// See [`TryPublicTagIter::offset`].
+    pub fn offset(&self) -> u32 {
+        self.cursor.position() as u32
+    }
+}
+
 impl<'a> Iterator for PublicTagIter<'a> {
     type Item = (u32, Tag<'a>);
 
@@ -278,29 +499,153 @@ impl<'a> Iterator for PublicTagIter<'a> {
     }
 }
 
-#[derive(Default)]
+pub struct TryPublicTagIter<'a> {
+    cursor: Cursor<'a>,
+    endian: Endian,
+    recover: bool,
+    /// End offsets of the scopes enclosing the next tag, innermost last
+    scopes: Vec<(u32, ErrorScope)>,
+    done: bool,
+    /// Location of the subsection data
+    location: Location,
+}
+
+impl<'a> TryPublicTagIter<'a> {
+    /// Enables recovery: after yielding an error, the iterator skips to the end of the innermost scope enclosing the tag
+    /// which failed, and goes on from there. Scopes whose end offset is not past the tag or is out of bounds are skipped
+    /// in favor of their parent. The iterator stops if no enclosing scope is left.
+    pub fn with_recovery(mut self) -> Self {
+        self.recover = true;
+        self
+    }
+
+    /// Returns the offset in the subsection data of the next tag, or after a tag failed to be read, the offset of the
+    /// byte where reading stopped.
+    pub fn offset(&self) -> u32 {
+        self.cursor.position() as u32
+    }
+
+    fn resync(&mut self, offset: u32) {
+        while let Some((end, _)) = self.scopes.pop() {
+            if end > offset && end as usize <= self.cursor.get_ref().len() {
+                self.cursor.set_position(end as usize);
+                return;
+            }
+        }
+        self.done = true;
+    }
+}
+
+impl<'a> Iterator for TryPublicTagIter<'a> {
+    type Item = Result<(u32, Tag<'a>), TagIterError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.cursor.position() as u32;
+        if self.done || self.cursor.remaining().is_empty() {
+            return None;
+        }
+        self.scopes.retain(|(end, _)| *end > offset);
+        match Tag::read(&mut self.cursor, self.endian) {
+            Ok(tag) => {
+                match &tag {
+                    Tag::File { end_offset } => self.scopes.push((*end_offset, ErrorScope::File)),
+                    Tag::Section { end_offset, sections } => {
+                        self.scopes.push((*end_offset, ErrorScope::Section(sections.clone())))
+                    }
+                    Tag::Symbol { end_offset, symbols } => {
+                        self.scopes.push((*end_offset, ErrorScope::Symbol(symbols.clone())))
+                    }
+                    _ => {}
+                }
+                Some(Ok((offset, tag)))
+            }
+            Err(error) => {
+                let location = Location {
+                    offset: self.location.offset + self.cursor.position() as u32,
+                    tag: peek_tag(self.cursor.get_ref(), offset),
+                    scope: self.scopes.last().map(|(_, scope)| scope.clone()),
+                    ..self.location.clone()
+                };
+                let error = TagIterError {
+                    location,
+                    start: self.location.offset + offset,
+                    error,
+                };
+                if self.recover {
+                    self.resync(offset);
+                } else {
+                    self.done = true;
+                }
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+/// Reads the number of the tag at `offset`, if possible.
+fn peek_tag(data: &[u8], offset: u32) -> Option<u32> {
+    let mut cursor = Cursor::new(data);
+    cursor.set_position(offset as usize);
+    read_uleb128(&mut cursor).ok()
+}
+
+/// Returns the scope enclosing the tag at `offset`, given the section and symbol scopes entered so far.
+fn scope_at(offset: u32, section: &Option<(u32, Vec<u32>)>, symbol: &Option<(u32, Vec<u32>)>) -> ErrorScope {
+    match (section, symbol) {
+        (_, Some((end, symbols))) if offset < *end => ErrorScope::Symbol(symbols.clone()),
+        (Some((end, sections)), _) if offset < *end => ErrorScope::Section(sections.clone()),
+        _ => ErrorScope::File,
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct File<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub attributes: Attributes<'a>,
+    /// Tags of the file scope in the order they appear, with their offsets in the subsection data. Unlike `attributes`,
+    /// every occurrence of a repeated tag is kept.
+    #[cfg_attr(feature = "serde", serde(borrow, default))]
+    pub tags: Vec<(u32, Tag<'a>)>,
     /// Maps list of section indices to a section group
-    pub sections: BTreeMap<&'a [u8], SectionGroup<'a>>,
+    #[cfg_attr(feature = "serde", serde(borrow, with = "serde_impls::index_map"))]
+    pub sections: BTreeMap<Vec<u32>, SectionGroup<'a>>,
 }
 
-#[derive(Default)]
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SectionGroup<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub attributes: Attributes<'a>,
+    /// Tags of the section scope in the order they appear, see [`File::tags`].
+    #[cfg_attr(feature = "serde", serde(borrow, default))]
+    pub tags: Vec<(u32, Tag<'a>)>,
+    /// Whether the attributes were inherited from the file scope, as this scope had none of its own
+    pub inherited: bool,
     /// Maps list of symbol values to a symbol group
-    pub symbols: BTreeMap<&'a [u8], SymbolGroup<'a>>,
+    #[cfg_attr(feature = "serde", serde(borrow, with = "serde_impls::index_map"))]
+    pub symbols: BTreeMap<Vec<u32>, SymbolGroup<'a>>,
 }
 
-#[derive(Default)]
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SymbolGroup<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub attributes: Attributes<'a>,
+    /// Tags of the symbol scope in the order they appear, see [`File::tags`].
+    #[cfg_attr(feature = "serde", serde(borrow, default))]
+    pub tags: Vec<(u32, Tag<'a>)>,
+    /// Whether the attributes were inherited from the section scope, as this scope had none of its own
+    pub inherited: bool,
 }
 
-#[derive(Default)]
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Attributes<'a> {
     // Target-related attributes
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub cpu_raw_name: Option<&'a str>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub cpu_name: Option<CpuName<'a>>,
     pub cpu_arch: Option<CpuArch>,
     pub cpu_arch_profile: Option<CpuArchProfile>,
@@ -346,10 +691,17 @@ pub struct Attributes<'a> {
     pub pacret_use: Option<PacretUse>,
     pub abi_opt_goals: Option<AbiOptGoals>,
     pub abi_fp_opt_goals: Option<AbiFpOptGoals>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub compat: Option<Compat<'a>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub also_compat_with: Option<AlsoCompatWith<'a>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub conform: Option<Conform<'a>>,
     pub no_defaults: bool,
+
+    /// Maps tag number to value of tags not known by this crate
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub unknown: BTreeMap<u32, UnknownValue<'a>>,
 }
 
 impl<'a> Attributes<'a> {
@@ -399,6 +751,136 @@ impl<'a> Attributes<'a> {
             && self.compat.is_none()
             && self.also_compat_with.is_none()
             && self.conform.is_none()
+            && self.unknown.is_empty()
+    }
+
+    /// Sets the attribute of a tag, replacing any previous value. Scope tags are ignored, as they are not attributes.
+    pub fn set(&mut self, tag: Tag<'a>) {
+        match tag {
+            Tag::File { .. } | Tag::Section { .. } | Tag::Symbol { .. } => {}
+            Tag::CpuRawName(x) => self.cpu_raw_name = Some(x),
+            Tag::CpuName(x) => self.cpu_name = Some(x),
+            Tag::CpuArch(x) => self.cpu_arch = Some(x),
+            Tag::CpuArchProfile(x) => self.cpu_arch_profile = Some(x),
+            Tag::ArmIsaUse(x) => self.arm_isa_use = Some(x),
+            Tag::ThumbIsaUse(x) => self.thumb_isa_use = Some(x),
+            Tag::FpArch(x) => self.fp_arch = Some(x),
+            Tag::WmmxArch(x) => self.wmmx_arch = Some(x),
+            Tag::AsimdArch(x) => self.asimd_arch = Some(x),
+            Tag::PcsConfig(x) => self.pcs_config = Some(x),
+            Tag::AbiPcsR9Use(x) => self.abi_pcs_r9_use = Some(x),
+            Tag::AbiPcsRwData(x) => self.abi_pcs_rw_data = Some(x),
+            Tag::AbiPcsRoData(x) => self.abi_pcs_ro_data = Some(x),
+            Tag::AbiPcsGotUse(x) => self.abi_pcs_got_use = Some(x),
+            Tag::AbiPcsWcharT(x) => self.abi_pcs_wchar_t = Some(x),
+            Tag::AbiFpRounding(x) => self.abi_fp_rounding = Some(x),
+            Tag::AbiFpDenormal(x) => self.abi_fp_denormal = Some(x),
+            Tag::AbiFpExceptions(x) => self.abi_fp_exceptions = Some(x),
+            Tag::AbiFpUserExceptions(x) => self.abi_fp_user_exceptions = Some(x),
+            Tag::AbiFpNumberModel(x) => self.abi_fp_number_model = Some(x),
+            Tag::AbiAlignNeeded(x) => self.abi_align_needed = Some(x),
+            Tag::AbiAlignPreserved(x) => self.abi_align_preserved = Some(x),
+            Tag::AbiEnumSize(x) => self.abi_enum_size = Some(x),
+            Tag::AbiHardFpUse(x) => self.abi_hardfp_use = Some(x),
+            Tag::AbiVfpArgs(x) => self.abi_vfp_args = Some(x),
+            Tag::AbiWmmxArgs(x) => self.abi_wmmx_args = Some(x),
+            Tag::AbiOptGoals(x) => self.abi_opt_goals = Some(x),
+            Tag::AbiFpOptGoals(x) => self.abi_fp_opt_goals = Some(x),
+            Tag::Compat(x) => self.compat = Some(x),
+            Tag::CpuUnalignedAccess(x) => self.cpu_unaligned_access = Some(x),
+            Tag::FpHpExt(x) => self.fp_hp_ext = Some(x),
+            Tag::AbiFp16BitFormat(x) => self.abi_fp_16bit_format = Some(x),
+            Tag::MpExtUse(x) => self.mp_ext_use = Some(x),
+            Tag::DivUse(x) => self.div_use = Some(x),
+            Tag::DspExt(x) => self.dsp_ext = Some(x),
+            Tag::MveArch(x) => self.mve_arch = Some(x),
+            Tag::PacExt(x) => self.pac_ext = Some(x),
+            Tag::BtiExt(x) => self.bti_ext = Some(x),
+            Tag::AlsoCompatWith(x) => self.also_compat_with = Some(x),
+            Tag::Conform(x) => self.conform = Some(x),
+            Tag::T2EeUse(x) => self.t2ee_use = Some(x),
+            Tag::VirtualUse(x) => self.virtual_use = Some(x),
+            Tag::FramePointerUse(x) => self.frame_pointer_use = Some(x),
+            Tag::BtiUse(x) => self.bti_use = Some(x),
+            Tag::PacretUse(x) => self.pacret_use = Some(x),
+            Tag::NoDefaults => self.no_defaults = true,
+            Tag::Unknown { tag, value } => {
+                self.unknown.insert(tag, value);
+            }
+        }
+    }
+
+    /// Returns the tags of this scope in ascending tag order, except for `Tag_conformance` which comes first as the ABI
+    /// recommends.
+    pub fn tags(&self) -> Vec<Tag<'a>> {
+        let mut tags = Vec::new();
+        macro_rules! push {
+            ($field:ident, $tag:ident) => {
+                if let Some(x) = self.$field {
+                    tags.push(Tag::$tag(x));
+                }
+            };
+        }
+        push!(cpu_raw_name, CpuRawName);
+        push!(cpu_name, CpuName);
+        push!(cpu_arch, CpuArch);
+        push!(cpu_arch_profile, CpuArchProfile);
+        push!(arm_isa_use, ArmIsaUse);
+        push!(thumb_isa_use, ThumbIsaUse);
+        push!(fp_arch, FpArch);
+        push!(wmmx_arch, WmmxArch);
+        push!(asimd_arch, AsimdArch);
+        push!(mve_arch, MveArch);
+        push!(fp_hp_ext, FpHpExt);
+        push!(cpu_unaligned_access, CpuUnalignedAccess);
+        push!(t2ee_use, T2EeUse);
+        push!(virtual_use, VirtualUse);
+        push!(mp_ext_use, MpExtUse);
+        push!(div_use, DivUse);
+        push!(dsp_ext, DspExt);
+        push!(pac_ext, PacExt);
+        push!(bti_ext, BtiExt);
+        push!(pcs_config, PcsConfig);
+        push!(abi_pcs_r9_use, AbiPcsR9Use);
+        push!(abi_pcs_rw_data, AbiPcsRwData);
+        push!(abi_pcs_ro_data, AbiPcsRoData);
+        push!(abi_pcs_got_use, AbiPcsGotUse);
+        push!(abi_pcs_wchar_t, AbiPcsWcharT);
+        push!(abi_enum_size, AbiEnumSize);
+        push!(abi_align_needed, AbiAlignNeeded);
+        push!(abi_align_preserved, AbiAlignPreserved);
+        push!(abi_fp_rounding, AbiFpRounding);
+        push!(abi_fp_denormal, AbiFpDenormal);
+        push!(abi_fp_exceptions, AbiFpExceptions);
+        push!(abi_fp_user_exceptions, AbiFpUserExceptions);
+        push!(abi_fp_number_model, AbiFpNumberModel);
+        push!(abi_fp_16bit_format, AbiFp16BitFormat);
+        push!(abi_hardfp_use, AbiHardFpUse);
+        push!(abi_vfp_args, AbiVfpArgs);
+        push!(abi_wmmx_args, AbiWmmxArgs);
+        push!(frame_pointer_use, FramePointerUse);
+        push!(bti_use, BtiUse);
+        push!(pacret_use, PacretUse);
+        push!(abi_opt_goals, AbiOptGoals);
+        push!(abi_fp_opt_goals, AbiFpOptGoals);
+        push!(compat, Compat);
+        if let Some(x) = &self.also_compat_with {
+            tags.push(Tag::AlsoCompatWith(x.clone()));
+        }
+        if self.no_defaults {
+            tags.push(Tag::NoDefaults);
+        }
+        for (tag, value) in &self.unknown {
+            tags.push(Tag::Unknown {
+                tag: *tag,
+                value: *value,
+            });
+        }
+        tags.sort_by_key(|tag| tag.raw_tag());
+        if let Some(x) = self.conform {
+            tags.insert(0, Tag::Conform(x));
+        }
+        tags
     }
 
     fn inherit(&mut self, from: &Attributes<'a>) {
@@ -454,9 +936,12 @@ impl<'a> Attributes<'a> {
             self.also_compat_with.clone_from(&from.also_compat_with);
         }
         inherit!(self, from, conform);
+        for (tag, value) in &from.unknown {
+            self.unknown.entry(*tag).or_insert(*value);
+        }
     }
 
-    pub fn display(&self, options: AttributeDisplayOptions) -> AttributeScopeDisplay {
+    pub fn display(&self, options: AttributeDisplayOptions) -> AttributeScopeDisplay<'_> {
         AttributeScopeDisplay { scope: self, options }
     }
 }
@@ -572,6 +1057,15 @@ impl<'a> fmt::Display for AttributeScopeDisplay<'a> {
             self.display_field(f, "Compatibility ........", &scope.compat)?;
             self.display_field(f, "Also compatible with .", &scope.also_compat_with)?;
             self.display_field(f, "Conformance ..........", &scope.conform)?;
+            for (tag, value) in &scope.unknown {
+                writeln!(
+                    f,
+                    "{}{:.<22} : {}",
+                    format_args!("{: >1$}", "", self.options.indent),
+                    format!("Tag {} ", tag),
+                    value
+                )?;
+            }
         }
         Ok(())
     }
@@ -0,0 +1,243 @@
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+use core::fmt;
+
+use crate::{enums::*, tag::Tag, Attributes, File};
+
+/// A suspicious attribute or combination of attributes, found by [`lint_attributes`] or [`lint_file`].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Diagnostic<'a> {
+    pub lint: Lint,
+    /// Attributes involved in the diagnostic
+    pub tags: Vec<Tag<'a>>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum Lint {
+    /// ARM instructions are allowed on an M-profile architecture, which only has the Thumb instruction set.
+    ArmIsaOnMProfile,
+    /// Tag_ABI_HardFP_use explicitly allows single or double precision FP use, but there is no FP architecture.
+    HardFpUseWithoutFpArch,
+    /// MVE is allowed on an architecture other than ARMv8.1-M.mainline.
+    MveWithoutV8_1M,
+    /// A deprecated tag like Tag_T2EE_use or Tag_nodefaults is used.
+    DeprecatedTag,
+    /// A section or symbol scope is used, which are deprecated.
+    DeprecatedScope,
+    /// Tag_CPU_name is a CPU whose architecture is not Tag_CPU_arch.
+    CpuNameMismatch,
+    /// A tag appears more than once in a scope with different values, of which only the last one is kept in the
+    /// scope's attributes. Only found by [`lint_file`].
+    ConflictingRepeatedTag,
+}
+
+impl Lint {
+    /// Returns the stable code of the lint, like "L001".
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::ArmIsaOnMProfile => "L001",
+            Self::HardFpUseWithoutFpArch => "L002",
+            Self::MveWithoutV8_1M => "L003",
+            Self::DeprecatedTag => "L004",
+            Self::DeprecatedScope => "L005",
+            Self::CpuNameMismatch => "L006",
+            Self::ConflictingRepeatedTag => "L007",
+        }
+    }
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ArmIsaOnMProfile => write!(f, "ARM instructions allowed on an M-profile architecture"),
+            Self::HardFpUseWithoutFpArch => write!(f, "Hardware FP use set without an FP architecture"),
+            Self::MveWithoutV8_1M => write!(f, "MVE allowed on an architecture other than ARMv8.1-M.mainline"),
+            Self::DeprecatedTag => write!(f, "Deprecated tag"),
+            Self::DeprecatedScope => write!(f, "Deprecated section or symbol scope"),
+            Self::CpuNameMismatch => write!(f, "CPU name does not match the CPU architecture"),
+            Self::ConflictingRepeatedTag => write!(f, "Tag repeated with conflicting values"),
+        }
+    }
+}
+
+/// Scope of the attributes a diagnostic of [`lint_file`] applies to.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum LintScope {
+    File,
+    /// Section scope with its section indices
+    Section(Vec<u32>),
+    /// Symbol scope with the section indices of its parent scope, and its symbol values
+    Symbol {
+        sections: Vec<u32>,
+        symbols: Vec<u32>,
+    },
+}
+
+impl fmt::Display for LintScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::File => write!(f, "file scope"),
+            Self::Section(sections) => write!(f, "section scope {:?}", sections),
+            Self::Symbol { sections, symbols } => write!(f, "symbol scope {:?} of section scope {:?}", symbols, sections),
+        }
+    }
+}
+
+/// Checks one set of attributes for suspicious values. Missing attributes are treated as having their default value.
+pub fn lint_attributes<'a>(attributes: &Attributes<'a>) -> Vec<Diagnostic<'a>> {
+    let mut diagnostics = Vec::new();
+    let mut push = |lint, tags: &[Option<Tag<'a>>]| {
+        let tags = tags.iter().flatten().cloned().collect();
+        diagnostics.push(Diagnostic { lint, tags });
+    };
+    let arch = attributes.cpu_arch.unwrap_or_default();
+    let cpu_arch = attributes.cpu_arch.map(Tag::CpuArch);
+
+    let m_profile = attributes.cpu_arch_profile == Some(CpuArchProfile::Microcontroller) || is_m_profile(arch);
+    if m_profile && attributes.arm_isa_use == Some(ArmIsaUse::Allowed) {
+        let profile = attributes.cpu_arch_profile.map(Tag::CpuArchProfile);
+        push(
+            Lint::ArmIsaOnMProfile,
+            &[profile, cpu_arch.clone(), Some(Tag::ArmIsaUse(ArmIsaUse::Allowed))],
+        );
+    }
+
+    // Using FP hardware as implied by Tag_FP_arch is consistent with having none. The reserved value was double-precision
+    // use in earlier versions of the ABI.
+    if let Some(hardfp_use @ (AbiHardFpUse::DerivedSingle | AbiHardFpUse::Reserved)) = attributes.abi_hardfp_use {
+        if attributes.fp_arch.unwrap_or_default() == FpArch::None {
+            let fp_arch = attributes.fp_arch.map(Tag::FpArch);
+            push(Lint::HardFpUseWithoutFpArch, &[Some(Tag::AbiHardFpUse(hardfp_use)), fp_arch]);
+        }
+    }
+
+    if let Some(mve_arch) = attributes.mve_arch.filter(|mve_arch| *mve_arch != MveArch::None) {
+        // Unknown architectures may be later M-profile ones
+        if !matches!(arch, CpuArch::V8_1MMainline | CpuArch::Unknown(_)) {
+            push(Lint::MveWithoutV8_1M, &[Some(Tag::MveArch(mve_arch)), cpu_arch.clone()]);
+        }
+    }
+
+    if let Some(t2ee_use) = attributes.t2ee_use {
+        push(Lint::DeprecatedTag, &[Some(Tag::T2EeUse(t2ee_use))]);
+    }
+    if attributes.no_defaults {
+        push(Lint::DeprecatedTag, &[Some(Tag::NoDefaults)]);
+    }
+
+    if let Some(cpu_name) = attributes.cpu_name {
+        if cpu_name_archs(cpu_name).is_some_and(|archs| !archs.contains(&arch)) {
+            push(Lint::CpuNameMismatch, &[Some(Tag::CpuName(cpu_name)), cpu_arch]);
+        }
+    }
+
+    diagnostics
+}
+
+/// Checks the attributes of every scope of a file for suspicious values, see [`lint_attributes`]. Scopes which inherited
+/// their attributes are only checked for being deprecated, as their attributes are those of their parent scope.
+pub fn lint_file<'a>(file: &File<'a>) -> Vec<(LintScope, Diagnostic<'a>)> {
+    let mut diagnostics = lint_diagnostics(LintScope::File, &file.attributes, &file.tags, false);
+    for (sections, section) in &file.sections {
+        let scope = LintScope::Section(sections.clone());
+        diagnostics.extend(lint_diagnostics(scope, &section.attributes, &section.tags, section.inherited));
+        for (symbols, symbol) in &section.symbols {
+            let scope = LintScope::Symbol {
+                sections: sections.clone(),
+                symbols: symbols.clone(),
+            };
+            diagnostics.extend(lint_diagnostics(scope, &symbol.attributes, &symbol.tags, symbol.inherited));
+        }
+    }
+    diagnostics
+}
+
+fn lint_diagnostics<'a>(
+    scope: LintScope,
+    attributes: &Attributes<'a>,
+    tags: &[(u32, Tag<'a>)],
+    inherited: bool,
+) -> Vec<(LintScope, Diagnostic<'a>)> {
+    let mut diagnostics = vec![];
+    if scope != LintScope::File {
+        let diagnostic = Diagnostic {
+            lint: Lint::DeprecatedScope,
+            tags: vec![],
+        };
+        diagnostics.push((scope.clone(), diagnostic));
+    }
+    if !inherited {
+        diagnostics.extend(
+            lint_attributes(attributes)
+                .into_iter()
+                .map(|diagnostic| (scope.clone(), diagnostic)),
+        );
+    }
+    let repeated = conflicting_repeated_tags(tags);
+    diagnostics.extend(repeated.into_iter().map(|diagnostic| (scope.clone(), diagnostic)));
+    diagnostics
+}
+
+/// Returns a diagnostic with the distinct values of each tag which has more than one value, in order of tag number.
+fn conflicting_repeated_tags<'a>(tags: &[(u32, Tag<'a>)]) -> Vec<Diagnostic<'a>> {
+    let mut values: BTreeMap<u32, Vec<Tag<'a>>> = BTreeMap::new();
+    for (_, tag) in tags {
+        let values = values.entry(tag.raw_tag()).or_default();
+        if !values.contains(tag) {
+            values.push(tag.clone());
+        }
+    }
+    values
+        .into_values()
+        .filter(|tags| tags.len() > 1)
+        .map(|tags| Diagnostic {
+            lint: Lint::ConflictingRepeatedTag,
+            tags,
+        })
+        .collect()
+}
+
+fn is_m_profile(arch: CpuArch) -> bool {
+    matches!(
+        arch,
+        CpuArch::V6M | CpuArch::V6SM | CpuArch::V7EM | CpuArch::V8MBaseline | CpuArch::V8MMainline | CpuArch::V8_1MMainline
+    )
+}
+
+/// Returns the architectures which toolchains record for a known CPU.
+fn cpu_name_archs(cpu_name: CpuName) -> Option<&'static [CpuArch]> {
+    use CpuArch::*;
+    Some(match cpu_name {
+        CpuName::None | CpuName::Other(_) => return None,
+        CpuName::Arm810 => &[V4],
+        CpuName::Arm7Tm
+        | CpuName::Arm7Tdm
+        | CpuName::Arm7Tdmi
+        | CpuName::Arm710T
+        | CpuName::Arm720T
+        | CpuName::Arm740T
+        | CpuName::Arm7TmS
+        | CpuName::Arm7TdmiS
+        | CpuName::Arm9Tdmi
+        | CpuName::Arm920T
+        | CpuName::Arm922T
+        | CpuName::Arm940T => &[V4T],
+        CpuName::Arm9ES | CpuName::Arm946ES | CpuName::Arm966ES | CpuName::Arm968ES | CpuName::Arm1020E => &[V5TE],
+        CpuName::Arm7TejS | CpuName::Arm9EjS | CpuName::Arm926EjS | CpuName::Arm1026EjS => &[V5TEJ],
+        CpuName::Arm1136JS | CpuName::Arm1136JfS => &[V6],
+        CpuName::Arm1156T2S | CpuName::Arm1156T2FS => &[V6T2],
+        CpuName::Arm1176JzS | CpuName::Arm1176JzfS => &[V6KZ],
+        CpuName::MpCore => &[V6K],
+        CpuName::CortexM0 | CpuName::CortexM0Plus | CpuName::CortexM1 | CpuName::Sc000 => &[V6M, V6SM],
+        CpuName::CortexM3 | CpuName::Sc300 => &[V7],
+        CpuName::CortexM4 => &[V7EM],
+        CpuName::CortexR4
+        | CpuName::CortexR4F
+        | CpuName::CortexR5
+        | CpuName::CortexR7
+        | CpuName::CortexA5
+        | CpuName::CortexA7
+        | CpuName::CortexA8
+        | CpuName::CortexA9
+        | CpuName::CortexA15 => &[V7],
+    })
+}
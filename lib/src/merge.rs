@@ -0,0 +1,555 @@
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+use core::fmt;
+
+use crate::{
+    enums::*,
+    tag::{Tag, UnknownValue},
+    Attributes,
+};
+
+/// Result of combining the attributes of several inputs, such as the objects of a link.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Merged<'a> {
+    pub attributes: Attributes<'a>,
+    pub conflicts: Vec<Conflict<'a>>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Conflict<'a> {
+    pub kind: ConflictKind,
+    /// Attributes involved in the conflict, each paired with the index of the input it came from. `None` means that the
+    /// input doesn't have the tag, so its default value was used.
+    pub attributes: Vec<(usize, Option<Tag<'a>>)>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum ConflictKind {
+    /// The attribute values cannot be combined.
+    Incompatible,
+    /// The first attribute needs an alignment of 8-byte data which the second attribute does not preserve.
+    AlignmentNotPreserved,
+    /// SB-relative RW data needs R9 as the static base, but the second attribute uses R9 differently.
+    R9Use,
+    /// The tag is not known, and its tag number (modulo 128) is below 64 which means it must be understood to be combined.
+    UnknownMandatoryTag,
+}
+
+impl fmt::Display for ConflictKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Incompatible => write!(f, "Incompatible values"),
+            Self::AlignmentNotPreserved => write!(f, "Needed alignment is not preserved"),
+            Self::R9Use => write!(f, "SB-relative data conflicts with R9 use"),
+            Self::UnknownMandatoryTag => write!(f, "Unknown mandatory tag"),
+        }
+    }
+}
+
+/// Combines the attributes of several inputs according to the ABI's rules for each tag. A missing tag is treated as having
+/// its default value. The merged attributes contain a tag if at least one input has it.
+pub fn merge<'a>(inputs: &[&Attributes<'a>]) -> Merged<'a> {
+    let mut merger = Merger {
+        inputs,
+        conflicts: Vec::new(),
+    };
+
+    // Target-related attributes
+    let cpu_arch = merger.field(|a| a.cpu_arch, Tag::CpuArch, combine_cpu_arch);
+    let arch = cpu_arch.unwrap_or_default();
+    let attributes = Attributes {
+        cpu_arch,
+        cpu_arch_profile: merger.field(|a| a.cpu_arch_profile, Tag::CpuArchProfile, combine_cpu_arch_profile),
+        cpu_name: merger.same_as_arch(arch, |a| a.cpu_name),
+        cpu_raw_name: merger.same_as_arch(arch, |a| a.cpu_raw_name),
+        arm_isa_use: merger.field(|a| a.arm_isa_use, Tag::ArmIsaUse, max),
+        thumb_isa_use: merger.field(|a| a.thumb_isa_use, Tag::ThumbIsaUse, max),
+        fp_arch: merger.field(|a| a.fp_arch, Tag::FpArch, combine_fp_arch),
+        wmmx_arch: merger.field(|a| a.wmmx_arch, Tag::WmmxArch, max),
+        asimd_arch: merger.field(|a| a.asimd_arch, Tag::AsimdArch, max),
+        mve_arch: merger.field(|a| a.mve_arch, Tag::MveArch, max),
+        fp_hp_ext: merger.field(|a| a.fp_hp_ext, Tag::FpHpExt, max),
+        cpu_unaligned_access: merger.field(|a| a.cpu_unaligned_access, Tag::CpuUnalignedAccess, max),
+        t2ee_use: merger.field(|a| a.t2ee_use, Tag::T2EeUse, max),
+        virtual_use: merger.field(|a| a.virtual_use, Tag::VirtualUse, combine_bits),
+        mp_ext_use: merger.field(|a| a.mp_ext_use, Tag::MpExtUse, max),
+        div_use: merger.field(|a| a.div_use, Tag::DivUse, max),
+        dsp_ext: merger.field(|a| a.dsp_ext, Tag::DspExt, max),
+        pac_ext: merger.field(|a| a.pac_ext, Tag::PacExt, max),
+        bti_ext: merger.field(|a| a.bti_ext, Tag::BtiExt, max),
+
+        // Procedure call-related attributes
+        pcs_config: merger.field(|a| a.pcs_config, Tag::PcsConfig, equal_or_default),
+        abi_pcs_r9_use: merger.field(|a| a.abi_pcs_r9_use, Tag::AbiPcsR9Use, combine_r9_use),
+        abi_pcs_rw_data: merger.field(|a| a.abi_pcs_rw_data, Tag::AbiPcsRwData, min),
+        abi_pcs_ro_data: merger.field(|a| a.abi_pcs_ro_data, Tag::AbiPcsRoData, min),
+        abi_pcs_got_use: merger.field(|a| a.abi_pcs_got_use, Tag::AbiPcsGotUse, max),
+        abi_pcs_wchar_t: merger.field(|a| a.abi_pcs_wchar_t, Tag::AbiPcsWcharT, equal_or_default),
+        abi_enum_size: merger.field(|a| a.abi_enum_size, Tag::AbiEnumSize, combine_enum_size),
+        abi_align_needed: merger.field(
+            |a| a.abi_align_needed,
+            Tag::AbiAlignNeeded,
+            |a, b| Some(if align_rank(b.value()) > align_rank(a.value()) { b } else { a }),
+        ),
+        abi_align_preserved: merger.field(
+            |a| a.abi_align_preserved,
+            Tag::AbiAlignPreserved,
+            |a, b| Some(if align_rank(b.value()) < align_rank(a.value()) { b } else { a }),
+        ),
+        abi_fp_rounding: merger.field(|a| a.abi_fp_rounding, Tag::AbiFpRounding, max),
+        abi_fp_denormal: merger.field(|a| a.abi_fp_denormal, Tag::AbiFpDenormal, combine_fp_denormal),
+        abi_fp_exceptions: merger.field(|a| a.abi_fp_exceptions, Tag::AbiFpExceptions, max),
+        abi_fp_user_exceptions: merger.field(|a| a.abi_fp_user_exceptions, Tag::AbiFpUserExceptions, max),
+        abi_fp_number_model: merger.field(|a| a.abi_fp_number_model, Tag::AbiFpNumberModel, max),
+        abi_fp_16bit_format: merger.field(|a| a.abi_fp_16bit_format, Tag::AbiFp16BitFormat, equal_or_default),
+        abi_hardfp_use: merger.field(|a| a.abi_hardfp_use, Tag::AbiHardFpUse, combine_hardfp_use),
+        abi_vfp_args: merger.vfp_args(),
+        abi_wmmx_args: merger.field(|a| a.abi_wmmx_args, Tag::AbiWmmxArgs, equal),
+        frame_pointer_use: merger.field(|a| a.frame_pointer_use, Tag::FramePointerUse, combine_frame_pointer_use),
+        bti_use: merger.field(|a| a.bti_use, Tag::BtiUse, min),
+
+        // Miscellaneous attributes
+        pacret_use: merger.field(|a| a.pacret_use, Tag::PacretUse, min),
+        abi_opt_goals: merger.field(|a| a.abi_opt_goals, Tag::AbiOptGoals, first_not_default),
+        abi_fp_opt_goals: merger.field(|a| a.abi_fp_opt_goals, Tag::AbiFpOptGoals, first_not_default),
+        compat: merger.field(|a| a.compat, Tag::Compat, equal_or_default),
+        also_compat_with: merger.same(|a| a.also_compat_with.as_ref()).cloned(),
+        conform: merger.same(|a| a.conform),
+        no_defaults: inputs.iter().any(|a| a.no_defaults),
+        unknown: merger.unknown(),
+    };
+    merger.alignment();
+    merger.r9_use();
+
+    Merged {
+        attributes,
+        conflicts: merger.conflicts,
+    }
+}
+
+/// A reason why two sets of attributes should not be linked together, see [`check_compatible`].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Incompatibility<'a> {
+    pub kind: ConflictKind,
+    pub severity: Severity,
+    /// Attribute of the first input involved in the incompatibility, or `None` if the input doesn't have it
+    pub a: Option<Tag<'a>>,
+    /// Attribute of the second input involved in the incompatibility, or `None` if the input doesn't have it
+    pub b: Option<Tag<'a>>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub enum Severity {
+    /// The objects may work together, for example if the affected types are not passed between them.
+    Warning,
+    /// The objects cannot work together.
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Warning => write!(f, "warning"),
+            Self::Error => write!(f, "error"),
+        }
+    }
+}
+
+impl<'a> From<Conflict<'a>> for Incompatibility<'a> {
+    fn from(conflict: Conflict<'a>) -> Self {
+        let tag = conflict.attributes.iter().find_map(|(_, tag)| tag.as_ref());
+        let severity = match (conflict.kind, tag) {
+            // These only matter if the affected types are used across the interface between the objects
+            (ConflictKind::Incompatible, Some(Tag::AbiPcsWcharT(_) | Tag::AbiEnumSize(_) | Tag::PcsConfig(_))) => {
+                Severity::Warning
+            }
+            _ => Severity::Error,
+        };
+        let find = |input| {
+            conflict
+                .attributes
+                .iter()
+                .find(|(index, _)| *index == input)
+                .and_then(|(_, tag)| tag.clone())
+        };
+        Self {
+            kind: conflict.kind,
+            severity,
+            a: find(0),
+            b: find(1),
+        }
+    }
+}
+
+/// Checks whether objects with the attributes `a` and `b` can be linked together, and returns the reasons why not.
+pub fn check_compatible<'a>(a: &Attributes<'a>, b: &Attributes<'a>) -> Vec<Incompatibility<'a>> {
+    merge(&[a, b]).conflicts.into_iter().map(Incompatibility::from).collect()
+}
+
+struct Merger<'a, 'b> {
+    inputs: &'b [&'b Attributes<'a>],
+    conflicts: Vec<Conflict<'a>>,
+}
+
+impl<'a, 'b> Merger<'a, 'b> {
+    /// Combines one attribute of all inputs pairwise, where `combine` returns `None` if two values conflict.
+    fn field<T, G, C>(&mut self, get: G, tag: fn(T) -> Tag<'a>, combine: C) -> Option<T>
+    where
+        T: Copy + Default + PartialEq,
+        G: Fn(&Attributes<'a>) -> Option<T>,
+        C: Fn(T, T) -> Option<T>,
+    {
+        if self.inputs.iter().all(|a| get(a).is_none()) {
+            return None;
+        }
+        let mut values = self.inputs.iter().map(|a| get(a)).enumerate();
+        let (mut merged_index, mut merged_input) = values.next()?;
+        let mut merged = merged_input.unwrap_or_default();
+        for (index, input) in values {
+            let value = input.unwrap_or_default();
+            match combine(merged, value) {
+                Some(result) => {
+                    if result != merged && result == value {
+                        merged_index = index;
+                        merged_input = input;
+                    }
+                    merged = result;
+                }
+                None => self.conflicts.push(Conflict {
+                    kind: ConflictKind::Incompatible,
+                    attributes: vec![(merged_index, merged_input.map(|_| tag(merged))), (index, input.map(tag))],
+                }),
+            }
+        }
+        Some(merged)
+    }
+
+    /// Returns the attribute if all inputs which have it agree on its value.
+    fn same<T: PartialEq>(&self, get: impl Fn(&'b Attributes<'a>) -> Option<T>) -> Option<T> {
+        let mut values = self.inputs.iter().filter_map(|a| get(a));
+        let first = values.next()?;
+        values.all(|value| value == first).then_some(first)
+    }
+
+    /// Returns the attribute if all inputs with the given CPU arch agree on its value, such as the CPU name.
+    fn same_as_arch<T: PartialEq>(&self, arch: CpuArch, get: impl Fn(&'b Attributes<'a>) -> Option<T>) -> Option<T> {
+        let mut values = self
+            .inputs
+            .iter()
+            .filter(|a| a.cpu_arch.unwrap_or_default() == arch)
+            .filter_map(|a| get(a));
+        let first = values.next()?;
+        values.all(|value| value == first).then_some(first)
+    }
+
+    fn vfp_args(&mut self) -> Option<AbiVfpArgs> {
+        if self.inputs.iter().all(|a| a.abi_vfp_args.is_none()) {
+            return None;
+        }
+        let mut merged: Option<(usize, Option<AbiVfpArgs>, AbiFpNumberModel)> = None;
+        for (index, input) in self.inputs.iter().enumerate() {
+            let number_model = input.abi_fp_number_model.unwrap_or_default();
+            let Some((merged_index, merged_input, merged_number_model)) = merged else {
+                merged = Some((index, input.abi_vfp_args, number_model));
+                continue;
+            };
+            let value = input.abi_vfp_args.unwrap_or_default();
+            let merged_value = merged_input.unwrap_or_default();
+            if value == merged_value || value == AbiVfpArgs::BaseVfp {
+                continue;
+            }
+            if merged_value == AbiVfpArgs::BaseVfp || merged_number_model == AbiFpNumberModel::None {
+                // The merged inputs don't pass FP arguments, so they adopt the calling convention of this input
+                merged = Some((index, input.abi_vfp_args, number_model));
+            } else if number_model != AbiFpNumberModel::None {
+                self.conflicts.push(Conflict {
+                    kind: ConflictKind::Incompatible,
+                    attributes: vec![
+                        (merged_index, merged_input.map(Tag::AbiVfpArgs)),
+                        (index, input.abi_vfp_args.map(Tag::AbiVfpArgs)),
+                    ],
+                });
+            }
+        }
+        merged.map(|(_, value, _)| value.unwrap_or_default())
+    }
+
+    fn alignment(&mut self) {
+        for (needed_index, needed_input) in self.inputs.iter().enumerate() {
+            let needed = needed_input.abi_align_needed.unwrap_or_default();
+            // 4-byte alignment of 8-byte data is preserved by all code
+            if matches!(needed, AbiAlignNeeded::None | AbiAlignNeeded::Align4) {
+                continue;
+            }
+            for (preserved_index, preserved_input) in self.inputs.iter().enumerate() {
+                let preserved = preserved_input.abi_align_preserved;
+                if needed_index != preserved_index
+                    && align_rank(preserved.unwrap_or_default().value()) < align_rank(needed.value())
+                {
+                    self.conflicts.push(Conflict {
+                        kind: ConflictKind::AlignmentNotPreserved,
+                        attributes: vec![
+                            (needed_index, Some(Tag::AbiAlignNeeded(needed))),
+                            (preserved_index, preserved.map(Tag::AbiAlignPreserved)),
+                        ],
+                    });
+                }
+            }
+        }
+    }
+
+    fn r9_use(&mut self) {
+        for (rw_index, rw_input) in self.inputs.iter().enumerate() {
+            if rw_input.abi_pcs_rw_data != Some(AbiPcsRwData::SbRel) {
+                continue;
+            }
+            for (r9_index, r9_input) in self.inputs.iter().enumerate() {
+                // Only an explicit use of R9 as a general or TLS register conflicts, not the default value
+                let Some(r9_use @ (AbiPcsR9Use::V6 | AbiPcsR9Use::TlsPointer)) = r9_input.abi_pcs_r9_use else {
+                    continue;
+                };
+                if rw_index != r9_index && !self.r9_use_conflicts(rw_index, r9_index) {
+                    self.conflicts.push(Conflict {
+                        kind: ConflictKind::R9Use,
+                        attributes: vec![
+                            (rw_index, Some(Tag::AbiPcsRwData(AbiPcsRwData::SbRel))),
+                            (r9_index, Some(Tag::AbiPcsR9Use(r9_use))),
+                        ],
+                    });
+                }
+            }
+        }
+    }
+
+    /// Returns whether the R9 use of the two inputs was already found to be incompatible.
+    fn r9_use_conflicts(&self, a: usize, b: usize) -> bool {
+        let is_r9_use = |conflict: &Conflict| {
+            let mut tags = conflict.attributes.iter().filter_map(|(_, tag)| tag.as_ref());
+            conflict.kind == ConflictKind::Incompatible && tags.any(|tag| matches!(tag, Tag::AbiPcsR9Use(_)))
+        };
+        let has = |conflict: &Conflict, input| conflict.attributes.iter().any(|(index, _)| *index == input);
+        self.conflicts
+            .iter()
+            .any(|conflict| is_r9_use(conflict) && has(conflict, a) && has(conflict, b))
+    }
+
+    fn unknown(&mut self) -> BTreeMap<u32, UnknownValue<'a>> {
+        let mut tags = BTreeMap::<u32, Vec<(usize, UnknownValue<'a>)>>::new();
+        for (index, input) in self.inputs.iter().enumerate() {
+            for (tag, value) in &input.unknown {
+                tags.entry(*tag).or_default().push((index, *value));
+            }
+        }
+
+        let mut merged = BTreeMap::new();
+        for (tag, values) in tags {
+            if tag % 128 < 64 {
+                self.conflicts.push(Conflict {
+                    kind: ConflictKind::UnknownMandatoryTag,
+                    attributes: values
+                        .iter()
+                        .map(|&(index, value)| (index, Some(Tag::Unknown { tag, value })))
+                        .collect(),
+                });
+            } else if values.iter().all(|(_, value)| *value == values[0].1) {
+                merged.insert(tag, values[0].1);
+            }
+        }
+        merged
+    }
+}
+
+/// Implemented for attribute values which are combined by comparing their numeric value.
+trait Value: Copy + From<u32> {
+    fn raw(self) -> u32;
+}
+
+macro_rules! impl_value {
+    ($($ty:ident),*) => {
+        $(
+            impl Value for $ty {
+                fn raw(self) -> u32 {
+                    self.value()
+                }
+            }
+        )*
+    };
+}
+
+impl_value!(
+    ArmIsaUse,
+    ThumbIsaUse,
+    FpArch,
+    WmmxArch,
+    AsimdArch,
+    MveArch,
+    FpHpExt,
+    CpuUnalignedAccess,
+    T2EeUse,
+    VirtualUse,
+    MpExtUse,
+    DivUse,
+    DspExt,
+    PacExt,
+    BtiExt,
+    AbiPcsRwData,
+    AbiPcsRoData,
+    AbiPcsGotUse,
+    AbiFpRounding,
+    AbiFpExceptions,
+    AbiFpUserExceptions,
+    AbiFpNumberModel,
+    AbiHardFpUse,
+    BtiUse,
+    PacretUse
+);
+
+fn max<T: Value>(a: T, b: T) -> Option<T> {
+    Some(if b.raw() > a.raw() { b } else { a })
+}
+
+fn min<T: Value>(a: T, b: T) -> Option<T> {
+    Some(if b.raw() < a.raw() { b } else { a })
+}
+
+/// Combines values where each bit is a separate capability, such as TrustZone and virtualization extensions.
+fn combine_bits<T: Value>(a: T, b: T) -> Option<T> {
+    Some(T::from(a.raw() | b.raw()))
+}
+
+fn equal<T: PartialEq>(a: T, b: T) -> Option<T> {
+    (a == b).then_some(a)
+}
+
+/// The default value is compatible with anything, but other values must be equal.
+fn equal_or_default<T: PartialEq + Default>(a: T, b: T) -> Option<T> {
+    if a == T::default() {
+        Some(b)
+    } else if b == T::default() || a == b {
+        Some(a)
+    } else {
+        None
+    }
+}
+
+fn first_not_default<T: PartialEq + Default>(a: T, b: T) -> Option<T> {
+    Some(if a == T::default() { b } else { a })
+}
+
+fn combine_cpu_arch(a: CpuArch, b: CpuArch) -> Option<CpuArch> {
+    fn rank(arch: CpuArch) -> u64 {
+        // Widened so that unknown architectures with large values don't overflow
+        let value = |arch: CpuArch| u64::from(arch.value()) * 4;
+        match arch {
+            // ARMv6-M and ARMv6S-M are subsets of ARMv7
+            CpuArch::V6M => value(CpuArch::V6K) + 1,
+            CpuArch::V6SM => value(CpuArch::V6K) + 2,
+            _ => value(arch),
+        }
+    }
+    Some(if rank(b) > rank(a) { b } else { a })
+}
+
+fn combine_cpu_arch_profile(a: CpuArchProfile, b: CpuArchProfile) -> Option<CpuArchProfile> {
+    match (a, b) {
+        _ if a == b => Some(a),
+        (CpuArchProfile::NotApplicable, _) => Some(b),
+        (_, CpuArchProfile::NotApplicable) => Some(a),
+        (CpuArchProfile::Classic, CpuArchProfile::Application | CpuArchProfile::RealTime) => Some(b),
+        (CpuArchProfile::Application | CpuArchProfile::RealTime, CpuArchProfile::Classic) => Some(a),
+        _ => None,
+    }
+}
+
+fn combine_fp_arch(a: FpArch, b: FpArch) -> Option<FpArch> {
+    /// Splits into the FP ISA version and the number of D registers
+    fn split(arch: FpArch) -> Option<(u32, u32)> {
+        match arch {
+            FpArch::None => Some((0, 0)),
+            FpArch::V1 => Some((1, 16)),
+            FpArch::V2 => Some((2, 16)),
+            FpArch::V3 => Some((3, 32)),
+            FpArch::V3Light => Some((3, 16)),
+            FpArch::V4 => Some((4, 32)),
+            FpArch::V4Light => Some((4, 16)),
+            FpArch::V8A => Some((8, 32)),
+            FpArch::V8ALight => Some((8, 16)),
+            FpArch::Unknown(_) => None,
+        }
+    }
+    let (Some((a_version, a_regs)), Some((b_version, b_regs))) = (split(a), split(b)) else {
+        return max(a, b);
+    };
+    Some(match (a_version.max(b_version), a_regs.max(b_regs)) {
+        (0, _) => FpArch::None,
+        (1, 16) => FpArch::V1,
+        (2, 16) => FpArch::V2,
+        // 32 D registers were introduced in v3
+        (1..=3, 32) => FpArch::V3,
+        (3, _) => FpArch::V3Light,
+        (4, 32) => FpArch::V4,
+        (4, _) => FpArch::V4Light,
+        (_, 32) => FpArch::V8A,
+        _ => FpArch::V8ALight,
+    })
+}
+
+fn combine_r9_use(a: AbiPcsR9Use, b: AbiPcsR9Use) -> Option<AbiPcsR9Use> {
+    match (a, b) {
+        _ if a == b => Some(a),
+        (AbiPcsR9Use::None, _) => Some(b),
+        (_, AbiPcsR9Use::None) => Some(a),
+        _ => None,
+    }
+}
+
+fn combine_enum_size(a: AbiEnumSize, b: AbiEnumSize) -> Option<AbiEnumSize> {
+    match (a, b) {
+        _ if a == b => Some(a),
+        // Enums which are 32-bit across interfaces are compatible with any enum size
+        (AbiEnumSize::None | AbiEnumSize::Visible32, _) => Some(b),
+        (_, AbiEnumSize::None | AbiEnumSize::Visible32) => Some(a),
+        _ => None,
+    }
+}
+
+/// Orders alignment values from weakest to strongest: none, 4-byte, 8-byte and then extended alignments.
+fn align_rank(value: u32) -> u32 {
+    match value {
+        0 => 0,
+        2 => 1,
+        1 => 2,
+        3 => 3,
+        _ => value,
+    }
+}
+
+fn combine_fp_denormal(a: AbiFpDenormal, b: AbiFpDenormal) -> Option<AbiFpDenormal> {
+    // Preserving the sign of flushed denormals is weaker than full IEEE 754 denormals
+    fn rank(value: AbiFpDenormal) -> u32 {
+        match value {
+            AbiFpDenormal::PreserveSign => 1,
+            AbiFpDenormal::Ieee754 => 2,
+            _ => value.value(),
+        }
+    }
+    Some(if rank(b) > rank(a) { b } else { a })
+}
+
+fn combine_hardfp_use(a: AbiHardFpUse, b: AbiHardFpUse) -> Option<AbiHardFpUse> {
+    match (a, b) {
+        _ if a == b => Some(a),
+        // Using FP hardware as implied by Tag_FP_arch includes single-precision use
+        (AbiHardFpUse::Implied | AbiHardFpUse::ImpliedOld, _) => Some(AbiHardFpUse::Implied),
+        (_, AbiHardFpUse::Implied | AbiHardFpUse::ImpliedOld) => Some(AbiHardFpUse::Implied),
+        _ => max(a, b),
+    }
+}
+
+fn combine_frame_pointer_use(a: FramePointerUse, b: FramePointerUse) -> Option<FramePointerUse> {
+    match (a, b) {
+        _ if a == b => Some(a),
+        (FramePointerUse::None, _) | (_, FramePointerUse::None) => Some(FramePointerUse::None),
+        // Code with frame records also preserves the frame pointer
+        _ => Some(FramePointerUse::WithoutRecords),
+    }
+}
@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use core::str::from_utf8;
 
 use crate::error::ReadError;
@@ -11,11 +12,17 @@ pub enum Endian {
 pub(crate) struct Cursor<'a> {
     pub(crate) data: &'a [u8],
     pub(crate) pos: usize,
+    /// Set when [`read_string`] reached the end of the data without finding a null
+    pub(crate) missing_null: bool,
 }
 
 impl<'a> Cursor<'a> {
     pub(crate) fn new(data: &'a [u8]) -> Self {
-        Self { data, pos: 0 }
+        Self {
+            data,
+            pos: 0,
+            missing_null: false,
+        }
     }
 
     pub(crate) fn read(&mut self, buf: &mut [u8]) -> usize {
@@ -44,7 +51,7 @@ impl<'a> Cursor<'a> {
     }
 
     pub(crate) fn get_ref(&self) -> &'a [u8] {
-        &self.data
+        self.data
     }
 
     pub(crate) fn remaining(&self) -> &'a [u8] {
@@ -52,10 +59,35 @@ impl<'a> Cursor<'a> {
     }
 }
 
-pub(crate) fn read_uleb128(cursor: &mut Cursor) -> Result<u8, ReadError> {
-    let mut buf = [0u8; 1];
-    cursor.read_exact(&mut buf)?;
-    Ok(buf[0] & 0x7f)
+/// Reads a ULEB128 value. On overflow, the cursor is left at the byte which overflowed.
+pub(crate) fn read_uleb128_u64(cursor: &mut Cursor) -> Result<u64, ReadError> {
+    let mut value = 0u64;
+    let mut shift = 0;
+    loop {
+        let byte = read_u8(cursor)?;
+        let bits = (byte & 0x7f) as u64;
+        if bits != 0 && (shift >= u64::BITS || (bits << shift) >> shift != bits) {
+            cursor.set_position(cursor.position() - 1);
+            return Err(ReadError::Overflow);
+        }
+        if shift < u64::BITS {
+            value |= bits << shift;
+        }
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+        shift += 7;
+    }
+}
+
+/// Reads a ULEB128 value. If it doesn't fit in a `u32`, the cursor is left at the start of the value.
+pub(crate) fn read_uleb128(cursor: &mut Cursor) -> Result<u32, ReadError> {
+    let pos = cursor.position();
+    let value = read_uleb128_u64(cursor)?;
+    u32::try_from(value).map_err(|_| {
+        cursor.set_position(pos);
+        ReadError::Overflow
+    })
 }
 
 pub(crate) fn read_u8(cursor: &mut Cursor) -> Result<u8, ReadError> {
@@ -64,11 +96,24 @@ pub(crate) fn read_u8(cursor: &mut Cursor) -> Result<u8, ReadError> {
     Ok(buf[0])
 }
 
-pub(crate) fn read_uleb128_list<'a>(cursor: &mut Cursor<'a>) -> Result<&'a [u8], ReadError> {
-    let data = cursor.remaining();
-    let len = data.iter().position(|x| *x == 0).unwrap_or(data.len());
-    cursor.set_position(cursor.position() + len + 1);
-    Ok(&data[..len])
+/// Reads ULEB128 values until a zero value terminates the list.
+pub(crate) fn read_uleb128_list(cursor: &mut Cursor) -> Result<Vec<u32>, ReadError> {
+    let mut values = Vec::new();
+    loop {
+        match read_uleb128(cursor)? {
+            0 => return Ok(values),
+            value => values.push(value),
+        }
+    }
+}
+
+pub(crate) fn read_u16(cursor: &mut Cursor, endian: Endian) -> Result<u16, ReadError> {
+    let mut buf = [0u8; 2];
+    cursor.read_exact(&mut buf)?;
+    Ok(match endian {
+        Endian::Little => u16::from_le_bytes(buf),
+        Endian::Big => u16::from_be_bytes(buf),
+    })
 }
 
 pub(crate) fn read_u32(cursor: &mut Cursor, endian: Endian) -> Result<u32, ReadError> {
@@ -80,9 +125,18 @@ pub(crate) fn read_u32(cursor: &mut Cursor, endian: Endian) -> Result<u32, ReadE
     })
 }
 
+/// Reads a null-terminated string, or the rest of the data if there is no null, which sets [`Cursor::missing_null`]. On
+/// invalid UTF-8, the cursor is left at the first invalid byte.
 pub(crate) fn read_string<'a>(cursor: &mut Cursor<'a>) -> Result<&'a str, ReadError> {
+    let pos = cursor.position();
     let data = cursor.remaining();
-    let len = data.iter().position(|x| *x == 0).unwrap_or(data.len());
-    cursor.set_position(cursor.position() + len + 1);
-    from_utf8(&data[..len]).map_err(ReadError::Utf8)
+    let len = data.iter().position(|x| *x == 0).unwrap_or_else(|| {
+        cursor.missing_null = true;
+        data.len()
+    });
+    cursor.set_position(pos + (len + 1).min(data.len()));
+    from_utf8(&data[..len]).map_err(|e| {
+        cursor.set_position(pos + e.valid_up_to());
+        ReadError::Utf8(e)
+    })
 }
@@ -0,0 +1,155 @@
+use serde::{ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::enums::*;
+
+#[derive(Deserialize)]
+struct NumericRepr {
+    value: u32,
+}
+
+#[derive(Deserialize)]
+struct StringRepr<'a> {
+    value: &'a str,
+    name: Option<&'a str>,
+}
+
+#[derive(Deserialize)]
+struct CompatRepr<'a> {
+    value: u32,
+    #[serde(default)]
+    vendor: &'a str,
+}
+
+/// Serializes enums with a numeric value as a struct containing the value and the variant name. Only the value is needed
+/// to deserialize, which makes unknown values round-trip.
+macro_rules! impl_serde_numeric {
+    ($($ty:ident),*) => {
+        $(
+            impl Serialize for $ty {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    let mut state = serializer.serialize_struct(stringify!($ty), 2)?;
+                    state.serialize_field("value", &self.value())?;
+                    state.serialize_field("name", self.name())?;
+                    state.end()
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    Ok(Self::from(NumericRepr::deserialize(deserializer)?.value))
+                }
+            }
+        )*
+    };
+}
+
+impl_serde_numeric!(
+    CpuArch,
+    CpuArchProfile,
+    ArmIsaUse,
+    ThumbIsaUse,
+    FpArch,
+    WmmxArch,
+    AsimdArch,
+    MveArch,
+    FpHpExt,
+    CpuUnalignedAccess,
+    T2EeUse,
+    VirtualUse,
+    MpExtUse,
+    DivUse,
+    DspExt,
+    PacExt,
+    BtiExt,
+    PcsConfig,
+    AbiPcsR9Use,
+    AbiPcsRwData,
+    AbiPcsRoData,
+    AbiPcsGotUse,
+    AbiPcsWcharT,
+    AbiEnumSize,
+    AbiAlignNeeded,
+    AbiAlignPreserved,
+    AbiFpRounding,
+    AbiFpDenormal,
+    AbiFpExceptions,
+    AbiFpUserExceptions,
+    AbiFpNumberModel,
+    AbiFp16BitFormat,
+    AbiHardFpUse,
+    AbiVfpArgs,
+    AbiWmmxArgs,
+    FramePointerUse,
+    BtiUse,
+    PacretUse,
+    AbiOptGoals,
+    AbiFpOptGoals
+);
+
+/// Serializes enums with a string value like [`impl_serde_numeric`]. The name is also used to deserialize the `None`
+/// variant, since its empty string would otherwise be read as an unknown value.
+macro_rules! impl_serde_string {
+    ($($ty:ident),*) => {
+        $(
+            impl<'a> Serialize for $ty<'a> {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    let mut state = serializer.serialize_struct(stringify!($ty), 2)?;
+                    state.serialize_field("value", self.as_str())?;
+                    state.serialize_field("name", self.name())?;
+                    state.end()
+                }
+            }
+
+            impl<'de: 'a, 'a> Deserialize<'de> for $ty<'a> {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let repr = StringRepr::deserialize(deserializer)?;
+                    match repr.name {
+                        Some("None") => Ok(Self::None),
+                        _ => Ok(Self::from(repr.value)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_serde_string!(CpuName, Conform);
+
+impl<'a> Serialize for Compat<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Compat", 3)?;
+        state.serialize_field("value", &self.value())?;
+        state.serialize_field("name", self.name())?;
+        match self {
+            Self::Always => state.skip_field("vendor")?,
+            Self::ByToolchain(vendor) | Self::Private { flag: _, vendor } => state.serialize_field("vendor", vendor)?,
+        }
+        state.end()
+    }
+}
+
+impl<'de: 'a, 'a> Deserialize<'de> for Compat<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = CompatRepr::deserialize(deserializer)?;
+        Ok(Self::new(repr.value, repr.vendor))
+    }
+}
+
+/// Serializes maps keyed by section or symbol indices as a sequence of key-value pairs, since formats like JSON only allow
+/// string keys.
+pub(crate) mod index_map {
+    use alloc::{collections::BTreeMap, vec::Vec};
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<V: Serialize, S: Serializer>(map: &BTreeMap<Vec<u32>, V>, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(map)
+    }
+
+    pub fn deserialize<'de, V: Deserialize<'de>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<BTreeMap<Vec<u32>, V>, D::Error> {
+        let entries = Vec::<(Vec<u32>, V)>::deserialize(deserializer)?;
+        Ok(entries.into_iter().collect())
+    }
+}
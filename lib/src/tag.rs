@@ -1,24 +1,29 @@
 #![allow(non_upper_case_globals)]
 
+use alloc::vec::Vec;
+use core::fmt;
+
 use crate::{
     enums::*,
     error::TagError,
     globals::*,
-    read::{read_string, read_u32, read_u8, read_uleb128, read_uleb128_list, Cursor, Endian},
+    read::{read_string, read_u32, read_u8, read_uleb128, read_uleb128_list, read_uleb128_u64, Cursor, Endian},
+    write::{write_string, write_u32, write_u8, write_uleb128, write_uleb128_list},
 };
 
 #[derive(Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Tag<'a> {
     /// Tag_File
     File { end_offset: u32 },
     /// Tag_Section
-    Section { end_offset: u32, sections: &'a [u8] },
+    Section { end_offset: u32, sections: Vec<u32> },
     /// Tag_Symbol
-    Symbol { end_offset: u32, symbols: &'a [u8] },
+    Symbol { end_offset: u32, symbols: Vec<u32> },
     /// Tag_CPU_raw_name
     CpuRawName(&'a str),
     /// Tag_CPU_name
-    CpuName(CpuName<'a>),
+    CpuName(#[cfg_attr(feature = "serde", serde(borrow))] CpuName<'a>),
     /// Tag_CPU_arch
     CpuArch(CpuArch),
     /// Tag_CPU_arch_profile
@@ -72,7 +77,7 @@ pub enum Tag<'a> {
     /// Tag_ABI_FP_optimization_goals
     AbiFpOptGoals(AbiFpOptGoals),
     /// Tag_compatibility
-    Compat(Compat<'a>),
+    Compat(#[cfg_attr(feature = "serde", serde(borrow))] Compat<'a>),
     /// Tag_CPU_unaligned_access
     CpuUnalignedAccess(CpuUnalignedAccess),
     /// Tag_FP_HP_extension
@@ -94,9 +99,9 @@ pub enum Tag<'a> {
     /// Tag_nodefaults
     NoDefaults,
     /// Tag_also_compatible_with
-    AlsoCompatWith(AlsoCompatWith<'a>),
+    AlsoCompatWith(#[cfg_attr(feature = "serde", serde(borrow))] AlsoCompatWith<'a>),
     /// Tag_conformance
-    Conform(Conform<'a>),
+    Conform(#[cfg_attr(feature = "serde", serde(borrow))] Conform<'a>),
     /// Tag_T2EE_use
     T2EeUse(T2EeUse),
     /// Tag_Virtualization_use
@@ -107,6 +112,88 @@ pub enum Tag<'a> {
     BtiUse(BtiUse),
     /// Tag_PACRET_use
     PacretUse(PacretUse),
+    /// Unrecognized tag above 32, skipped according to its tag number
+    Unknown {
+        tag: u32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        value: UnknownValue<'a>,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UnknownValue<'a> {
+    /// Even-numbered tags have a ULEB128 value.
+    Uleb128(u64),
+    /// Odd-numbered tags have a null-terminated string value.
+    String(&'a str),
+}
+
+impl<'a> fmt::Display for UnknownValue<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Uleb128(value) => write!(f, "{}", value),
+            Self::String(value) => write!(f, "\"{}\"", value),
+        }
+    }
+}
+
+/// Displays the value of the tag.
+impl<'a> fmt::Display for Tag<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Tag::File { end_offset: _ } => write!(f, "File scope"),
+            Tag::Section { end_offset: _, sections } => write!(f, "Sections {:?}", sections),
+            Tag::Symbol { end_offset: _, symbols } => write!(f, "Symbols {:?}", symbols),
+            Tag::CpuRawName(x) => write!(f, "\"{}\"", x),
+            Tag::CpuName(x) => write!(f, "{}", x),
+            Tag::CpuArch(x) => write!(f, "{}", x),
+            Tag::CpuArchProfile(x) => write!(f, "{}", x),
+            Tag::ArmIsaUse(x) => write!(f, "{}", x),
+            Tag::ThumbIsaUse(x) => write!(f, "{}", x),
+            Tag::FpArch(x) => write!(f, "{}", x),
+            Tag::WmmxArch(x) => write!(f, "{}", x),
+            Tag::AsimdArch(x) => write!(f, "{}", x),
+            Tag::PcsConfig(x) => write!(f, "{}", x),
+            Tag::AbiPcsR9Use(x) => write!(f, "{}", x),
+            Tag::AbiPcsRwData(x) => write!(f, "{}", x),
+            Tag::AbiPcsRoData(x) => write!(f, "{}", x),
+            Tag::AbiPcsGotUse(x) => write!(f, "{}", x),
+            Tag::AbiPcsWcharT(x) => write!(f, "{}", x),
+            Tag::AbiFpRounding(x) => write!(f, "{}", x),
+            Tag::AbiFpDenormal(x) => write!(f, "{}", x),
+            Tag::AbiFpExceptions(x) => write!(f, "{}", x),
+            Tag::AbiFpUserExceptions(x) => write!(f, "{}", x),
+            Tag::AbiFpNumberModel(x) => write!(f, "{}", x),
+            Tag::AbiAlignNeeded(x) => write!(f, "{}", x),
+            Tag::AbiAlignPreserved(x) => write!(f, "{}", x),
+            Tag::AbiEnumSize(x) => write!(f, "{}", x),
+            Tag::AbiHardFpUse(x) => write!(f, "{}", x),
+            Tag::AbiVfpArgs(x) => write!(f, "{}", x),
+            Tag::AbiWmmxArgs(x) => write!(f, "{}", x),
+            Tag::AbiOptGoals(x) => write!(f, "{}", x),
+            Tag::AbiFpOptGoals(x) => write!(f, "{}", x),
+            Tag::Compat(x) => write!(f, "{}", x),
+            Tag::CpuUnalignedAccess(x) => write!(f, "{}", x),
+            Tag::FpHpExt(x) => write!(f, "{}", x),
+            Tag::AbiFp16BitFormat(x) => write!(f, "{}", x),
+            Tag::MpExtUse(x) => write!(f, "{}", x),
+            Tag::DivUse(x) => write!(f, "{}", x),
+            Tag::DspExt(x) => write!(f, "{}", x),
+            Tag::MveArch(x) => write!(f, "{}", x),
+            Tag::PacExt(x) => write!(f, "{}", x),
+            Tag::BtiExt(x) => write!(f, "{}", x),
+            Tag::NoDefaults => write!(f, "No defaults"),
+            Tag::AlsoCompatWith(x) => write!(f, "{}", x),
+            Tag::Conform(x) => write!(f, "{}", x),
+            Tag::T2EeUse(x) => write!(f, "{}", x),
+            Tag::VirtualUse(x) => write!(f, "{}", x),
+            Tag::FramePointerUse(x) => write!(f, "{}", x),
+            Tag::BtiUse(x) => write!(f, "{}", x),
+            Tag::PacretUse(x) => write!(f, "{}", x),
+            Tag::Unknown { tag: _, value } => write!(f, "{}", value),
+        }
+    }
 }
 
 impl<'a> Tag<'a> {
@@ -127,6 +214,10 @@ impl<'a> Tag<'a> {
                 | Tag::Compat(_)
                 | Tag::AlsoCompatWith(_)
                 | Tag::Conform(_)
+                | Tag::Unknown {
+                    tag: _,
+                    value: UnknownValue::String(_)
+                }
         )
     }
 
@@ -145,8 +236,9 @@ impl<'a> Tag<'a> {
         )
     }
 
-    pub fn raw_tag(&self) -> u8 {
-        match self {
+    pub fn raw_tag(&self) -> u32 {
+        let tag = match self {
+            Tag::Unknown { tag, value: _ } => return *tag,
             Tag::File { end_offset: _ } => Tag_File,
             Tag::Section {
                 end_offset: _,
@@ -202,12 +294,170 @@ impl<'a> Tag<'a> {
             Tag::FramePointerUse(_) => Tag_FramePointer_use,
             Tag::BtiUse(_) => Tag_BTI_use,
             Tag::PacretUse(_) => Tag_PACRET_use,
+        };
+        tag.into()
+    }
+
+    /// Returns the name of the tag, like `Tag_CPU_arch`, or `None` if the tag is unknown.
+    pub fn name(&self) -> Option<&'static str> {
+        tag_name(self.raw_tag())
+    }
+
+    /// Creates a tag which has a ULEB128 value, or returns `None` if the tag has another kind of value. Unknown even tags
+    /// above 32 are supported, as are `Tag_nodefaults` and `Tag_compatibility` with a value of 0.
+    pub fn from_uleb128(tag: u32, value: u64) -> Option<Self> {
+        let Ok(raw_tag) = u8::try_from(tag) else {
+            return (tag & 1 == 0).then_some(Tag::Unknown {
+                tag,
+                value: UnknownValue::Uleb128(value),
+            });
+        };
+        let x = u32::try_from(value);
+        let tag = match raw_tag {
+            Tag_CPU_arch => Tag::CpuArch(CpuArch::from(x.ok()?)),
+            Tag_CPU_arch_profile => Tag::CpuArchProfile(CpuArchProfile::from(x.ok()?)),
+            Tag_ARM_ISA_use => Tag::ArmIsaUse(ArmIsaUse::from(x.ok()?)),
+            Tag_THUMB_ISA_use => Tag::ThumbIsaUse(ThumbIsaUse::from(x.ok()?)),
+            Tag_FP_arch => Tag::FpArch(FpArch::from(x.ok()?)),
+            Tag_WMMX_arch => Tag::WmmxArch(WmmxArch::from(x.ok()?)),
+            Tag_Advanced_SIMD_arch => Tag::AsimdArch(AsimdArch::from(x.ok()?)),
+            Tag_PCS_config => Tag::PcsConfig(PcsConfig::from(x.ok()?)),
+            Tag_ABI_PCS_R9_use => Tag::AbiPcsR9Use(AbiPcsR9Use::from(x.ok()?)),
+            Tag_ABI_PCS_RW_data => Tag::AbiPcsRwData(AbiPcsRwData::from(x.ok()?)),
+            Tag_ABI_PCS_RO_data => Tag::AbiPcsRoData(AbiPcsRoData::from(x.ok()?)),
+            Tag_ABI_PCS_GOT_use => Tag::AbiPcsGotUse(AbiPcsGotUse::from(x.ok()?)),
+            Tag_ABI_PCS_wchar_t => Tag::AbiPcsWcharT(AbiPcsWcharT::from(x.ok()?)),
+            Tag_ABI_FP_rounding => Tag::AbiFpRounding(AbiFpRounding::from(x.ok()?)),
+            Tag_ABI_FP_denormal => Tag::AbiFpDenormal(AbiFpDenormal::from(x.ok()?)),
+            Tag_ABI_FP_exceptions => Tag::AbiFpExceptions(AbiFpExceptions::from(x.ok()?)),
+            Tag_ABI_FP_user_exceptions => Tag::AbiFpUserExceptions(AbiFpUserExceptions::from(x.ok()?)),
+            Tag_ABI_FP_number_model => Tag::AbiFpNumberModel(AbiFpNumberModel::from(x.ok()?)),
+            Tag_ABI_align_needed => Tag::AbiAlignNeeded(AbiAlignNeeded::from(x.ok()?)),
+            Tag_ABI_align_preserved => Tag::AbiAlignPreserved(AbiAlignPreserved::from(x.ok()?)),
+            Tag_ABI_enum_size => Tag::AbiEnumSize(AbiEnumSize::from(x.ok()?)),
+            Tag_ABI_HardFP_use => Tag::AbiHardFpUse(AbiHardFpUse::from(x.ok()?)),
+            Tag_ABI_VFP_args => Tag::AbiVfpArgs(AbiVfpArgs::from(x.ok()?)),
+            Tag_ABI_WMMX_args => Tag::AbiWmmxArgs(AbiWmmxArgs::from(x.ok()?)),
+            Tag_ABI_optimization_goals => Tag::AbiOptGoals(AbiOptGoals::from(x.ok()?)),
+            Tag_ABI_FP_optimization_goals => Tag::AbiFpOptGoals(AbiFpOptGoals::from(x.ok()?)),
+            Tag_compatibility if value == 0 => Tag::Compat(Compat::new(0, "")),
+            Tag_CPU_unaligned_access => Tag::CpuUnalignedAccess(CpuUnalignedAccess::from(x.ok()?)),
+            Tag_FP_HP_extension => Tag::FpHpExt(FpHpExt::from(x.ok()?)),
+            Tag_ABI_FP_16bit_format => Tag::AbiFp16BitFormat(AbiFp16BitFormat::from(x.ok()?)),
+            Tag_MPextension_use => Tag::MpExtUse(MpExtUse::from(x.ok()?)),
+            Tag_DIV_use => Tag::DivUse(DivUse::from(x.ok()?)),
+            Tag_DSP_extension => Tag::DspExt(DspExt::from(x.ok()?)),
+            Tag_MVE_arch => Tag::MveArch(MveArch::from(x.ok()?)),
+            Tag_PAC_extension => Tag::PacExt(PacExt::from(x.ok()?)),
+            Tag_BTI_extension => Tag::BtiExt(BtiExt::from(x.ok()?)),
+            Tag_nodefaults if value == 0 => Tag::NoDefaults,
+            Tag_T2EE_use => Tag::T2EeUse(T2EeUse::from(x.ok()?)),
+            Tag_Virtualization_use => Tag::VirtualUse(VirtualUse::from(x.ok()?)),
+            Tag_FramePointer_use => Tag::FramePointerUse(FramePointerUse::from(x.ok()?)),
+            Tag_BTI_use => Tag::BtiUse(BtiUse::from(x.ok()?)),
+            Tag_PACRET_use => Tag::PacretUse(PacretUse::from(x.ok()?)),
+            _ if tag > Tag_compatibility.into() && tag & 1 == 0 && tag != Tag_nodefaults.into() => Tag::Unknown {
+                tag,
+                value: UnknownValue::Uleb128(value),
+            },
+            _ => return None,
+        };
+        Some(tag)
+    }
+
+    /// Returns the ULEB128 value of the tag, or `None` if the tag has another kind of value. This is the inverse of
+    /// [`Tag::from_uleb128`].
+    pub fn uleb128_value(&self) -> Option<u64> {
+        let value = match self {
+            Tag::CpuArch(x) => x.value(),
+            Tag::CpuArchProfile(x) => x.value(),
+            Tag::ArmIsaUse(x) => x.value(),
+            Tag::ThumbIsaUse(x) => x.value(),
+            Tag::FpArch(x) => x.value(),
+            Tag::WmmxArch(x) => x.value(),
+            Tag::AsimdArch(x) => x.value(),
+            Tag::PcsConfig(x) => x.value(),
+            Tag::AbiPcsR9Use(x) => x.value(),
+            Tag::AbiPcsRwData(x) => x.value(),
+            Tag::AbiPcsRoData(x) => x.value(),
+            Tag::AbiPcsGotUse(x) => x.value(),
+            Tag::AbiPcsWcharT(x) => x.value(),
+            Tag::AbiFpRounding(x) => x.value(),
+            Tag::AbiFpDenormal(x) => x.value(),
+            Tag::AbiFpExceptions(x) => x.value(),
+            Tag::AbiFpUserExceptions(x) => x.value(),
+            Tag::AbiFpNumberModel(x) => x.value(),
+            Tag::AbiAlignNeeded(x) => x.value(),
+            Tag::AbiAlignPreserved(x) => x.value(),
+            Tag::AbiEnumSize(x) => x.value(),
+            Tag::AbiHardFpUse(x) => x.value(),
+            Tag::AbiVfpArgs(x) => x.value(),
+            Tag::AbiWmmxArgs(x) => x.value(),
+            Tag::AbiOptGoals(x) => x.value(),
+            Tag::AbiFpOptGoals(x) => x.value(),
+            Tag::Compat(Compat::Always) => 0,
+            Tag::CpuUnalignedAccess(x) => x.value(),
+            Tag::FpHpExt(x) => x.value(),
+            Tag::AbiFp16BitFormat(x) => x.value(),
+            Tag::MpExtUse(x) => x.value(),
+            Tag::DivUse(x) => x.value(),
+            Tag::DspExt(x) => x.value(),
+            Tag::MveArch(x) => x.value(),
+            Tag::PacExt(x) => x.value(),
+            Tag::BtiExt(x) => x.value(),
+            Tag::NoDefaults => 0,
+            Tag::T2EeUse(x) => x.value(),
+            Tag::VirtualUse(x) => x.value(),
+            Tag::FramePointerUse(x) => x.value(),
+            Tag::BtiUse(x) => x.value(),
+            Tag::PacretUse(x) => x.value(),
+            Tag::Unknown {
+                tag: _,
+                value: UnknownValue::Uleb128(value),
+            } => return Some(*value),
+            _ => return None,
+        };
+        Some(value.into())
+    }
+
+    /// Returns the string value of the tag, or `None` if the tag has another kind of value. This is the inverse of
+    /// [`Tag::from_string`].
+    pub fn string_value(&self) -> Option<&'a str> {
+        match self {
+            Tag::CpuRawName(x) => Some(x),
+            Tag::CpuName(x) => Some(x.as_str()),
+            Tag::Conform(x) => Some(x.as_str()),
+            Tag::Unknown {
+                tag: _,
+                value: UnknownValue::String(value),
+            } => Some(value),
+            _ => None,
         }
     }
 
+    /// Creates a tag which has a string value, or returns `None` if the tag has another kind of value. Unknown odd tags above
+    /// 32 are supported.
+    pub fn from_string(tag: u32, value: &'a str) -> Option<Self> {
+        let tag = match u8::try_from(tag) {
+            Ok(Tag_CPU_raw_name) => Tag::CpuRawName(value),
+            Ok(Tag_CPU_name) => Tag::CpuName(CpuName::from(value)),
+            Ok(Tag_conformance) => Tag::Conform(Conform::from(value)),
+            Ok(Tag_also_compatible_with) => return None,
+            _ if tag > Tag_compatibility.into() && tag & 1 == 1 => Tag::Unknown {
+                tag,
+                value: UnknownValue::String(value),
+            },
+            _ => return None,
+        };
+        Some(tag)
+    }
+
     pub(crate) fn read(cursor: &mut Cursor<'a>, endian: Endian) -> Result<Self, TagError> {
         let pos = cursor.position() as u32;
-        let tag = read_uleb128(cursor).map_err(TagError::Read)?;
+        let raw_tag = read_uleb128(cursor).map_err(TagError::Read)?;
+        let Ok(tag) = u8::try_from(raw_tag) else {
+            return Self::read_unknown(cursor, raw_tag, pos);
+        };
         let tag = match tag {
             Tag_File => Tag::File {
                 end_offset: pos + read_u32(cursor, endian).map_err(TagError::Read)?,
@@ -285,19 +535,23 @@ impl<'a> Tag<'a> {
             Tag_nodefaults => {
                 let ignored = read_u8(cursor).map_err(TagError::Read)?;
                 if ignored != 0 {
+                    cursor.set_position(cursor.position() - 1);
                     return Err(TagError::ExpectedNull);
                 }
                 Tag::NoDefaults
             }
             Tag_also_compatible_with => {
+                let sub_pos = cursor.position();
                 let sub_tag = Tag::read(cursor, endian)?;
                 if sub_tag.is_uleb128() {
                     let null = read_u8(cursor).map_err(TagError::Read)?;
                     if null != 0 {
+                        cursor.set_position(cursor.position() - 1);
                         return Err(TagError::ExpectedNull);
                     }
                 }
                 if sub_tag.is_scope() {
+                    cursor.set_position(sub_pos);
                     return Err(TagError::NestedScopeTag);
                 }
                 Tag::AlsoCompatWith(AlsoCompatWith::new(sub_tag))
@@ -308,8 +562,110 @@ impl<'a> Tag<'a> {
             Tag_FramePointer_use => Tag::FramePointerUse(FramePointerUse::from(read_uleb128(cursor).map_err(TagError::Read)?)),
             Tag_BTI_use => Tag::BtiUse(BtiUse::from(read_uleb128(cursor).map_err(TagError::Read)?)),
             Tag_PACRET_use => Tag::PacretUse(PacretUse::from(read_uleb128(cursor).map_err(TagError::Read)?)),
-            _ => return Err(TagError::IncompatibleTagValue(tag)),
+            _ => return Self::read_unknown(cursor, raw_tag, pos),
         };
         Ok(tag)
     }
+
+    fn read_unknown(cursor: &mut Cursor<'a>, tag: u32, pos: u32) -> Result<Self, TagError> {
+        // Only tags above 32 follow the convention which lets us skip them
+        if tag <= Tag_compatibility.into() {
+            cursor.set_position(pos as usize);
+            return Err(TagError::IncompatibleTagValue(tag));
+        }
+        let value = if tag & 1 == 0 {
+            UnknownValue::Uleb128(read_uleb128_u64(cursor).map_err(TagError::Read)?)
+        } else {
+            UnknownValue::String(read_string(cursor).map_err(TagError::Read)?)
+        };
+        Ok(Tag::Unknown { tag, value })
+    }
+
+    /// Encodes this tag at the end of `buf`. `base` is the position in `buf` where the subsection data begins, which is
+    /// needed to convert the end offset of scope tags into a size.
+    pub(crate) fn write(&self, buf: &mut Vec<u8>, base: usize, endian: Endian) {
+        if let Tag::AlsoCompatWith(AlsoCompatWith::None) = self {
+            // Has no encoding, as there is no tag to be compatible with
+            return;
+        }
+        let pos = (buf.len() - base) as u32;
+        write_uleb128(buf, self.raw_tag().into());
+        match self {
+            Tag::File { end_offset } => write_u32(buf, end_offset.wrapping_sub(pos), endian),
+            Tag::Section { end_offset, sections } => {
+                write_u32(buf, end_offset.wrapping_sub(pos), endian);
+                write_uleb128_list(buf, sections);
+            }
+            Tag::Symbol { end_offset, symbols } => {
+                write_u32(buf, end_offset.wrapping_sub(pos), endian);
+                write_uleb128_list(buf, symbols);
+            }
+            Tag::CpuRawName(x) => write_string(buf, x),
+            Tag::CpuName(x) => write_string(buf, x.as_str()),
+            Tag::CpuArch(x) => write_uleb128(buf, x.value().into()),
+            Tag::CpuArchProfile(x) => write_uleb128(buf, x.value().into()),
+            Tag::ArmIsaUse(x) => write_uleb128(buf, x.value().into()),
+            Tag::ThumbIsaUse(x) => write_uleb128(buf, x.value().into()),
+            Tag::FpArch(x) => write_uleb128(buf, x.value().into()),
+            Tag::WmmxArch(x) => write_uleb128(buf, x.value().into()),
+            Tag::AsimdArch(x) => write_uleb128(buf, x.value().into()),
+            Tag::PcsConfig(x) => write_uleb128(buf, x.value().into()),
+            Tag::AbiPcsR9Use(x) => write_uleb128(buf, x.value().into()),
+            Tag::AbiPcsRwData(x) => write_uleb128(buf, x.value().into()),
+            Tag::AbiPcsRoData(x) => write_uleb128(buf, x.value().into()),
+            Tag::AbiPcsGotUse(x) => write_uleb128(buf, x.value().into()),
+            Tag::AbiPcsWcharT(x) => write_uleb128(buf, x.value().into()),
+            Tag::AbiFpRounding(x) => write_uleb128(buf, x.value().into()),
+            Tag::AbiFpDenormal(x) => write_uleb128(buf, x.value().into()),
+            Tag::AbiFpExceptions(x) => write_uleb128(buf, x.value().into()),
+            Tag::AbiFpUserExceptions(x) => write_uleb128(buf, x.value().into()),
+            Tag::AbiFpNumberModel(x) => write_uleb128(buf, x.value().into()),
+            Tag::AbiAlignNeeded(x) => write_uleb128(buf, x.value().into()),
+            Tag::AbiAlignPreserved(x) => write_uleb128(buf, x.value().into()),
+            Tag::AbiEnumSize(x) => write_uleb128(buf, x.value().into()),
+            Tag::AbiHardFpUse(x) => write_uleb128(buf, x.value().into()),
+            Tag::AbiVfpArgs(x) => write_uleb128(buf, x.value().into()),
+            Tag::AbiWmmxArgs(x) => write_uleb128(buf, x.value().into()),
+            Tag::AbiOptGoals(x) => write_uleb128(buf, x.value().into()),
+            Tag::AbiFpOptGoals(x) => write_uleb128(buf, x.value().into()),
+            Tag::CpuUnalignedAccess(x) => write_uleb128(buf, x.value().into()),
+            Tag::FpHpExt(x) => write_uleb128(buf, x.value().into()),
+            Tag::AbiFp16BitFormat(x) => write_uleb128(buf, x.value().into()),
+            Tag::MpExtUse(x) => write_uleb128(buf, x.value().into()),
+            Tag::DivUse(x) => write_uleb128(buf, x.value().into()),
+            Tag::DspExt(x) => write_uleb128(buf, x.value().into()),
+            Tag::MveArch(x) => write_uleb128(buf, x.value().into()),
+            Tag::PacExt(x) => write_uleb128(buf, x.value().into()),
+            Tag::BtiExt(x) => write_uleb128(buf, x.value().into()),
+            Tag::T2EeUse(x) => write_uleb128(buf, x.value().into()),
+            Tag::VirtualUse(x) => write_uleb128(buf, x.value().into()),
+            Tag::FramePointerUse(x) => write_uleb128(buf, x.value().into()),
+            Tag::BtiUse(x) => write_uleb128(buf, x.value().into()),
+            Tag::PacretUse(x) => write_uleb128(buf, x.value().into()),
+            Tag::Compat(x) => {
+                write_uleb128(buf, x.value().into());
+                match x {
+                    Compat::Always => {}
+                    Compat::ByToolchain(vendor) | Compat::Private { flag: _, vendor } => write_string(buf, vendor),
+                }
+            }
+            Tag::NoDefaults => write_u8(buf, 0),
+            Tag::AlsoCompatWith(x) => {
+                let sub_tag = match x {
+                    AlsoCompatWith::Arch(arch) => &Tag::CpuArch(*arch),
+                    AlsoCompatWith::Reserved(tag) => tag.as_ref(),
+                    AlsoCompatWith::None => unreachable!(),
+                };
+                sub_tag.write(buf, base, endian);
+                if sub_tag.is_uleb128() {
+                    write_u8(buf, 0);
+                }
+            }
+            Tag::Conform(x) => write_string(buf, x.as_str()),
+            Tag::Unknown { tag: _, value } => match value {
+                UnknownValue::Uleb128(value) => write_uleb128(buf, *value),
+                UnknownValue::String(value) => write_string(buf, value),
+            },
+        }
+    }
 }
@@ -0,0 +1,173 @@
+#![allow(non_upper_case_globals)]
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{
+    error::TagError,
+    globals::*,
+    read::{read_string, read_u32, read_uleb128, read_uleb128_list, read_uleb128_u64, Cursor, Endian},
+};
+
+/// How the value of a vendor attribute is encoded.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum ValueType {
+    Uleb128,
+    /// Null-terminated string
+    String,
+    /// ULEB128 followed by a null-terminated string, like Tag_compatibility
+    Uleb128String,
+}
+
+impl ValueType {
+    /// The ABI's convention for tags above 32, where even tags have a ULEB128 value and odd tags a string value.
+    pub fn from_parity(tag: u32) -> Self {
+        if tag & 1 == 0 {
+            Self::Uleb128
+        } else {
+            Self::String
+        }
+    }
+}
+
+/// Decodes the attributes of a vendor subsection. The scope tags Tag_File, Tag_Section and Tag_Symbol are read the same way
+/// for all vendors, so a parser only has to know how the values of the other tags are encoded.
+pub trait VendorParser {
+    /// Returns how the value of `tag` is encoded. By default, the odd/even convention is used for all tags.
+    fn value_type(&self, tag: u32) -> ValueType {
+        ValueType::from_parity(tag)
+    }
+
+    /// Returns the name of `tag`, if it is known.
+    fn tag_name(&self, _tag: u32) -> Option<&'static str> {
+        None
+    }
+}
+
+/// Fallback for vendors without a parser, which decodes all tags using the odd/even convention.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct GenericParser;
+
+impl VendorParser for GenericParser {}
+
+/// Parser for the "gnu" subsection emitted by GCC and binutils.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct GnuParser;
+
+impl VendorParser for GnuParser {
+    fn value_type(&self, tag: u32) -> ValueType {
+        if tag == Tag_compatibility.into() {
+            ValueType::Uleb128String
+        } else {
+            ValueType::from_parity(tag)
+        }
+    }
+
+    fn tag_name(&self, tag: u32) -> Option<&'static str> {
+        (tag == Tag_compatibility.into()).then_some("Tag_compatibility")
+    }
+}
+
+/// Returns the built-in parser for a vendor name, or [`GenericParser`] if there is none.
+pub fn default_parser(vendor_name: &str) -> &'static dyn VendorParser {
+    match vendor_name {
+        "gnu" => &GnuParser,
+        _ => &GenericParser,
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub enum VendorTag<'a> {
+    /// Tag_File
+    File {
+        end_offset: u32,
+    },
+    /// Tag_Section
+    Section {
+        end_offset: u32,
+        sections: Vec<u32>,
+    },
+    /// Tag_Symbol
+    Symbol {
+        end_offset: u32,
+        symbols: Vec<u32>,
+    },
+    Attribute {
+        tag: u32,
+        value: VendorValue<'a>,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum VendorValue<'a> {
+    Uleb128(u64),
+    String(&'a str),
+    Uleb128String(u64, &'a str),
+}
+
+impl<'a> fmt::Display for VendorValue<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Uleb128(value) => write!(f, "{}", value),
+            Self::String(value) => write!(f, "\"{}\"", value),
+            Self::Uleb128String(value, string) => write!(f, "{}, \"{}\"", value, string),
+        }
+    }
+}
+
+impl<'a> VendorTag<'a> {
+    pub(crate) fn read(cursor: &mut Cursor<'a>, endian: Endian, parser: &dyn VendorParser) -> Result<Self, TagError> {
+        let pos = cursor.position() as u32;
+        let tag = read_uleb128(cursor).map_err(TagError::Read)?;
+        let tag = match u8::try_from(tag) {
+            Ok(Tag_File) => VendorTag::File {
+                end_offset: pos + read_u32(cursor, endian).map_err(TagError::Read)?,
+            },
+            Ok(Tag_Section) => VendorTag::Section {
+                end_offset: pos + read_u32(cursor, endian).map_err(TagError::Read)?,
+                sections: read_uleb128_list(cursor).map_err(TagError::Read)?,
+            },
+            Ok(Tag_Symbol) => VendorTag::Symbol {
+                end_offset: pos + read_u32(cursor, endian).map_err(TagError::Read)?,
+                symbols: read_uleb128_list(cursor).map_err(TagError::Read)?,
+            },
+            _ => {
+                let value = match parser.value_type(tag) {
+                    ValueType::Uleb128 => VendorValue::Uleb128(read_uleb128_u64(cursor).map_err(TagError::Read)?),
+                    ValueType::String => VendorValue::String(read_string(cursor).map_err(TagError::Read)?),
+                    ValueType::Uleb128String => VendorValue::Uleb128String(
+                        read_uleb128_u64(cursor).map_err(TagError::Read)?,
+                        read_string(cursor).map_err(TagError::Read)?,
+                    ),
+                };
+                VendorTag::Attribute { tag, value }
+            }
+        };
+        Ok(tag)
+    }
+}
+
+pub struct VendorTagIter<'a, 'p> {
+    pub(crate) cursor: Cursor<'a>,
+    pub(crate) endian: Endian,
+    pub(crate) parser: &'p dyn VendorParser,
+}
+
+impl<'a, 'p> VendorTagIter<'a, 'p> {
+    /// See [`TryPublicTagIter::offset`](crate::TryPublicTagIter::offset).
+    pub fn offset(&self) -> u32 {
+        self.cursor.position() as u32
+    }
+}
+
+impl<'a, 'p> Iterator for VendorTagIter<'a, 'p> {
+    type Item = (u32, VendorTag<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.cursor.position() as u32;
+        match VendorTag::read(&mut self.cursor, self.endian, self.parser) {
+            Ok(tag) => Some((offset, tag)),
+            Err(_) => None,
+        }
+    }
+}
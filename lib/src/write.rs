@@ -0,0 +1,169 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{globals::*, read::Endian, tag::Tag, Attributes, File};
+
+pub(crate) fn write_uleb128(buf: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            buf.push(byte);
+            return;
+        }
+        buf.push(byte | 0x80);
+    }
+}
+
+/// Writes ULEB128 values followed by a zero value which terminates the list.
+pub(crate) fn write_uleb128_list(buf: &mut Vec<u8>, values: &[u32]) {
+    for &value in values {
+        write_uleb128(buf, value.into());
+    }
+    buf.push(0);
+}
+
+pub(crate) fn write_u8(buf: &mut Vec<u8>, value: u8) {
+    buf.push(value);
+}
+
+pub(crate) fn write_u32(buf: &mut Vec<u8>, value: u32, endian: Endian) {
+    match endian {
+        Endian::Little => buf.extend_from_slice(&value.to_le_bytes()),
+        Endian::Big => buf.extend_from_slice(&value.to_be_bytes()),
+    }
+}
+
+pub(crate) fn write_string(buf: &mut Vec<u8>, value: &str) {
+    buf.extend_from_slice(value.as_bytes());
+    buf.push(0);
+}
+
+/// Overwrites a `u32` previously written at `pos`, used to fill in sizes after their contents have been written.
+pub(crate) fn patch_u32(buf: &mut [u8], pos: usize, value: u32, endian: Endian) {
+    let bytes = match endian {
+        Endian::Little => value.to_le_bytes(),
+        Endian::Big => value.to_be_bytes(),
+    };
+    buf[pos..pos + 4].copy_from_slice(&bytes);
+}
+
+/// Encodes build attributes into the contents of an `.ARM.attributes` section.
+pub struct BuildAttrsWriter {
+    data: Vec<u8>,
+    endian: Endian,
+}
+
+impl BuildAttrsWriter {
+    pub fn new(endian: Endian) -> Self {
+        Self {
+            data: vec![b'A'],
+            endian,
+        }
+    }
+
+    /// Adds a subsection with already encoded contents.
+    pub fn add_subsection(&mut self, vendor_name: &str, data: &[u8]) {
+        let start = self.begin_subsection(vendor_name);
+        self.data.extend_from_slice(data);
+        self.end_subsection(start);
+    }
+
+    /// Adds an "aeabi" subsection containing the given tags as they are. The end offsets of scope tags are kept, so they must
+    /// be correct for the encoded tags.
+    pub fn add_public_tags(&mut self, tags: &[Tag]) {
+        let start = self.begin_subsection("aeabi");
+        let base = self.data.len();
+        for tag in tags {
+            tag.write(&mut self.data, base, self.endian);
+        }
+        self.end_subsection(start);
+    }
+
+    /// Adds an "aeabi" subsection containing all scopes of `file`. Scope sizes are computed from the encoded attributes. Scopes
+    /// with inherited attributes are written empty, so that they inherit them again when read.
+    ///
+    /// The `tags` of a scope are written in their stored order, including repeated tags, if they still match its attributes.
+    /// Otherwise, for example after changing the attributes, the attributes are written in order of tag number.
+    pub fn add_public_attributes(&mut self, file: &File) {
+        let start = self.begin_subsection("aeabi");
+        let base = self.data.len();
+
+        let file_scope = self.begin_scope(Tag_File, None);
+        self.write_scope_attributes(&file.attributes, &file.tags, false, base);
+        for (sections, section) in &file.sections {
+            let section_scope = self.begin_scope(Tag_Section, Some(sections));
+            self.write_scope_attributes(&section.attributes, &section.tags, section.inherited, base);
+            for (symbols, symbol) in &section.symbols {
+                let symbol_scope = self.begin_scope(Tag_Symbol, Some(symbols));
+                self.write_scope_attributes(&symbol.attributes, &symbol.tags, symbol.inherited, base);
+                self.end_scope(symbol_scope);
+            }
+            self.end_scope(section_scope);
+        }
+        self.end_scope(file_scope);
+
+        self.end_subsection(start);
+    }
+
+    pub fn finish(self) -> Vec<u8> {
+        self.data
+    }
+
+    fn begin_subsection(&mut self, vendor_name: &str) -> usize {
+        let start = self.data.len();
+        write_u32(&mut self.data, 0, self.endian);
+        write_string(&mut self.data, vendor_name);
+        start
+    }
+
+    fn end_subsection(&mut self, start: usize) {
+        let length = (self.data.len() - start) as u32;
+        patch_u32(&mut self.data, start, length, self.endian);
+    }
+
+    fn begin_scope(&mut self, tag: u8, indices: Option<&[u32]>) -> usize {
+        let start = self.data.len();
+        write_uleb128(&mut self.data, tag.into());
+        write_u32(&mut self.data, 0, self.endian);
+        if let Some(indices) = indices {
+            write_uleb128_list(&mut self.data, indices);
+        }
+        start
+    }
+
+    fn end_scope(&mut self, start: usize) {
+        let size = (self.data.len() - start) as u32;
+        // Scope tags are less than 128, so their ULEB128 encoding is a single byte
+        patch_u32(&mut self.data, start + 1, size, self.endian);
+    }
+
+    fn write_attributes(&mut self, attributes: &Attributes, base: usize) {
+        for tag in attributes.tags() {
+            tag.write(&mut self.data, base, self.endian);
+        }
+    }
+
+    fn write_scope_attributes(&mut self, attributes: &Attributes, tags: &[(u32, Tag)], inherited: bool, base: usize) {
+        if inherited {
+            if attributes.no_defaults {
+                // Inherited attributes are not written, but the scope's own `Tag_nodefaults` still is
+                Tag::NoDefaults.write(&mut self.data, base, self.endian);
+            }
+        } else if !tags.is_empty() && tags_match(tags, attributes) {
+            for (_, tag) in tags {
+                tag.write(&mut self.data, base, self.endian);
+            }
+        } else {
+            self.write_attributes(attributes, base);
+        }
+    }
+}
+
+/// Returns whether setting `tags` in order results in `attributes`.
+fn tags_match(tags: &[(u32, Tag)], attributes: &Attributes) -> bool {
+    let mut replayed = Attributes::default();
+    for (_, tag) in tags {
+        replayed.set(tag.clone());
+    }
+    replayed == *attributes
+}
@@ -0,0 +1,94 @@
+#![allow(dead_code)]
+
+use arm_attr::read::Endian;
+
+pub const SHT_PROGBITS: u32 = 1;
+pub const SHT_STRTAB: u32 = 3;
+pub const SHT_REL: u32 = 9;
+pub const SHT_ARM_ATTRIBUTES: u32 = 0x70000003;
+pub const SHF_COMPRESSED: u32 = 0x800;
+pub const EM_ARM: u16 = 40;
+
+pub struct Section<'a> {
+    pub name: &'a str,
+    pub kind: u32,
+    pub flags: u32,
+    pub data: &'a [u8],
+}
+
+/// Builds a relocatable ELF32 file containing the given sections, followed by a section name string table.
+pub fn elf32(endian: Endian, machine: u16, sections: &[Section]) -> Vec<u8> {
+    let u16_bytes = |value: u16| match endian {
+        Endian::Little => value.to_le_bytes(),
+        Endian::Big => value.to_be_bytes(),
+    };
+    let u32_bytes = |value: u32| match endian {
+        Endian::Little => value.to_le_bytes(),
+        Endian::Big => value.to_be_bytes(),
+    };
+
+    let mut shstrtab = vec![0];
+    let mut names = vec![];
+    for section in sections.iter().map(|s| s.name).chain([".shstrtab"]) {
+        names.push(shstrtab.len() as u32);
+        shstrtab.extend_from_slice(section.as_bytes());
+        shstrtab.push(0);
+    }
+
+    let mut data = vec![0; 52];
+    let mut headers = vec![[0u32; 10]];
+    for (section, name) in sections.iter().zip(&names) {
+        headers.push([
+            *name,
+            section.kind,
+            section.flags,
+            0,
+            data.len() as u32,
+            section.data.len() as u32,
+            0,
+            0,
+            1,
+            0,
+        ]);
+        data.extend_from_slice(section.data);
+    }
+    headers.push([
+        names[sections.len()],
+        SHT_STRTAB,
+        0,
+        0,
+        data.len() as u32,
+        shstrtab.len() as u32,
+        0,
+        0,
+        1,
+        0,
+    ]);
+    data.extend_from_slice(&shstrtab);
+    data.resize(data.len().next_multiple_of(4), 0);
+
+    let shoff = data.len() as u32;
+    for header in &headers {
+        for field in header {
+            data.extend_from_slice(&u32_bytes(*field));
+        }
+    }
+
+    let mut ehdr = vec![0x7f, b'E', b'L', b'F', 1, if endian == Endian::Little { 1 } else { 2 }, 1];
+    ehdr.resize(16, 0);
+    ehdr.extend_from_slice(&u16_bytes(1)); // e_type: relocatable
+    ehdr.extend_from_slice(&u16_bytes(machine));
+    ehdr.extend_from_slice(&u32_bytes(1)); // e_version
+    ehdr.extend_from_slice(&u32_bytes(0)); // e_entry
+    ehdr.extend_from_slice(&u32_bytes(0)); // e_phoff
+    ehdr.extend_from_slice(&u32_bytes(shoff));
+    ehdr.extend_from_slice(&u32_bytes(0x05000000)); // e_flags: EABI version 5
+    ehdr.extend_from_slice(&u16_bytes(52)); // e_ehsize
+    ehdr.extend_from_slice(&u16_bytes(0)); // e_phentsize
+    ehdr.extend_from_slice(&u16_bytes(0)); // e_phnum
+    ehdr.extend_from_slice(&u16_bytes(40)); // e_shentsize
+    ehdr.extend_from_slice(&u16_bytes(headers.len() as u16));
+    ehdr.extend_from_slice(&u16_bytes(headers.len() as u16 - 1)); // e_shstrndx
+    data[..52].copy_from_slice(&ehdr);
+    data
+}
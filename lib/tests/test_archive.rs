@@ -0,0 +1,142 @@
+mod common;
+
+use arm_attr::{
+    archive::{Archive, ArchiveMember},
+    enums::*,
+    error::ArchiveError,
+    globals::*,
+    read::Endian,
+    BuildAttrs,
+};
+use common::*;
+
+#[rustfmt::skip]
+const RAW: &[u8] = &[
+    b'A', // version
+    0x11, 0x00, 0x00, 0x00, // size
+    b'a', b'e', b'a', b'b', b'i', 0, // "aeabi" subsection
+    Tag_File, 0x07, 0x00, 0x00, 0x00, // file scope
+        Tag_CPU_arch, 4, // V5TE
+];
+
+fn object() -> Vec<u8> {
+    elf32(
+        Endian::Little,
+        EM_ARM,
+        &[Section {
+            name: ".ARM.attributes",
+            kind: SHT_ARM_ATTRIBUTES,
+            flags: 0,
+            data: RAW,
+        }],
+    )
+}
+
+/// Appends a member header and its data, padded to an even size.
+fn add_member(archive: &mut Vec<u8>, name: &str, size: usize, data: &[u8]) {
+    archive.extend_from_slice(format!("{name:<16}{:<12}{:<6}{:<6}{:<8}{size:<10}`\n", 0, 0, 0, 644).as_bytes());
+    archive.extend_from_slice(data);
+    if archive.len() % 2 == 1 {
+        archive.push(b'\n');
+    }
+}
+
+#[test]
+fn test_gnu_archive() {
+    let object = object();
+    let names = b"a_very_long_member_name.o/\n";
+    let mut archive = b"!<arch>\n".to_vec();
+    add_member(&mut archive, "/", 4, &[0; 4]);
+    add_member(&mut archive, "//", names.len(), names);
+    add_member(&mut archive, "short.o/", object.len(), &object);
+    add_member(&mut archive, "/0", object.len(), &object);
+    add_member(&mut archive, "readme.txt/", 3, b"hi\n");
+
+    let members = Archive::parse(&archive)
+        .unwrap()
+        .members()
+        .map(|m| m.unwrap().name)
+        .collect::<Vec<_>>();
+    assert_eq!(members, ["short.o", "a_very_long_member_name.o", "readme.txt"]);
+
+    let build_attrs = BuildAttrs::from_archive(&archive)
+        .unwrap()
+        .map(|m| m.unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(build_attrs.len(), 2);
+    assert_eq!(build_attrs[1].0, "a_very_long_member_name.o");
+    let subsection = build_attrs[1].1.subsections().next().unwrap().unwrap();
+    let file = subsection.into_public_attributes().unwrap();
+    assert_eq!(file.attributes.cpu_arch, Some(CpuArch::V5TE));
+}
+
+#[test]
+fn test_bsd_archive() {
+    let object = object();
+    let mut archive = b"!<arch>\n".to_vec();
+    add_member(&mut archive, "#1/20", 24, b"__.SYMDEF SORTED\0\0\0\0\0\0\0\0");
+    let mut data = b"a_very_long_name.o\0\0".to_vec();
+    data.extend_from_slice(&object);
+    add_member(&mut archive, "#1/20", data.len(), &data);
+    add_member(&mut archive, "short.o", object.len(), &object);
+
+    let archive = Archive::parse(&archive).unwrap();
+    let mut members = archive.members().map(|m| m.unwrap());
+    assert_eq!(
+        members.next(),
+        Some(ArchiveMember {
+            name: "a_very_long_name.o",
+            data: Some(&object),
+        })
+    );
+    assert_eq!(members.next().unwrap().name, "short.o");
+    assert_eq!(members.next(), None);
+    assert_eq!(archive.build_attrs().count(), 2);
+}
+
+#[test]
+fn test_thin_archive() {
+    let names = b"dir/a_very_long_member_name.o/\n";
+    let mut archive = b"!<thin>\n".to_vec();
+    add_member(&mut archive, "/", 4, &[0; 4]);
+    add_member(&mut archive, "//", names.len(), names);
+    add_member(&mut archive, "/0", 1234, &[]);
+    add_member(&mut archive, "short.o/", 5678, &[]);
+
+    let archive = Archive::parse(&archive).unwrap();
+    assert!(archive.is_thin());
+    let members = archive.members().map(|m| m.unwrap()).collect::<Vec<_>>();
+    assert_eq!(
+        members,
+        [
+            ArchiveMember {
+                name: "dir/a_very_long_member_name.o",
+                data: None,
+            },
+            ArchiveMember {
+                name: "short.o",
+                data: None,
+            },
+        ]
+    );
+    assert!(matches!(
+        archive.build_attrs().next(),
+        Some(Err(ArchiveError::ThinMember(name))) if name == "dir/a_very_long_member_name.o"
+    ));
+}
+
+#[test]
+fn test_archive_errors() {
+    assert!(matches!(Archive::parse(b"\x7fELF"), Err(ArchiveError::NotArchive)));
+
+    let mut archive = b"!<arch>\n".to_vec();
+    add_member(&mut archive, "short.o/", 100, &[0; 10]);
+    let mut members = Archive::parse(&archive).unwrap().members();
+    assert!(matches!(members.next(), Some(Err(ArchiveError::InvalidHeader))));
+    assert!(members.next().is_none());
+
+    let mut archive = b"!<arch>\n".to_vec();
+    add_member(&mut archive, "/20", 0, &[]);
+    let mut members = Archive::parse(&archive).unwrap().members();
+    assert!(matches!(members.next(), Some(Err(ArchiveError::InvalidName))));
+}
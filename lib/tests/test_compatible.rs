@@ -0,0 +1,127 @@
+use arm_attr::{
+    enums::*,
+    merge::{check_compatible, ConflictKind, Incompatibility, Severity},
+    tag::Tag,
+    Attributes,
+};
+
+#[test]
+fn test_compatible() {
+    let a = Attributes {
+        cpu_arch: Some(CpuArch::V5TE),
+        abi_pcs_wchar_t: Some(AbiPcsWcharT::Size4),
+        abi_vfp_args: Some(AbiVfpArgs::Base),
+        ..Default::default()
+    };
+    let b = Attributes {
+        cpu_arch: Some(CpuArch::V7),
+        abi_pcs_wchar_t: Some(AbiPcsWcharT::Size4),
+        ..Default::default()
+    };
+    assert_eq!(check_compatible(&a, &b), vec![]);
+}
+
+#[test]
+fn test_incompatible() {
+    let a = Attributes {
+        abi_pcs_wchar_t: Some(AbiPcsWcharT::Size2),
+        abi_enum_size: Some(AbiEnumSize::SmallestSize),
+        abi_vfp_args: Some(AbiVfpArgs::Vfp),
+        abi_fp_number_model: Some(AbiFpNumberModel::All),
+        abi_align_preserved: Some(AbiAlignPreserved::None),
+        ..Default::default()
+    };
+    let b = Attributes {
+        abi_pcs_wchar_t: Some(AbiPcsWcharT::Size4),
+        abi_enum_size: Some(AbiEnumSize::Always32),
+        abi_align_needed: Some(AbiAlignNeeded::Align8),
+        abi_fp_number_model: Some(AbiFpNumberModel::All),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        check_compatible(&a, &b),
+        vec![
+            Incompatibility {
+                kind: ConflictKind::Incompatible,
+                severity: Severity::Warning,
+                a: Some(Tag::AbiPcsWcharT(AbiPcsWcharT::Size2)),
+                b: Some(Tag::AbiPcsWcharT(AbiPcsWcharT::Size4)),
+            },
+            Incompatibility {
+                kind: ConflictKind::Incompatible,
+                severity: Severity::Warning,
+                a: Some(Tag::AbiEnumSize(AbiEnumSize::SmallestSize)),
+                b: Some(Tag::AbiEnumSize(AbiEnumSize::Always32)),
+            },
+            Incompatibility {
+                kind: ConflictKind::Incompatible,
+                severity: Severity::Error,
+                a: Some(Tag::AbiVfpArgs(AbiVfpArgs::Vfp)),
+                b: None,
+            },
+            Incompatibility {
+                kind: ConflictKind::AlignmentNotPreserved,
+                severity: Severity::Error,
+                a: Some(Tag::AbiAlignPreserved(AbiAlignPreserved::None)),
+                b: Some(Tag::AbiAlignNeeded(AbiAlignNeeded::Align8)),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_incompatible_r9() {
+    let a = Attributes {
+        abi_pcs_rw_data: Some(AbiPcsRwData::SbRel),
+        abi_pcs_r9_use: Some(AbiPcsR9Use::Sb),
+        ..Default::default()
+    };
+    let b = Attributes {
+        abi_pcs_r9_use: Some(AbiPcsR9Use::TlsPointer),
+        ..Default::default()
+    };
+
+    let incompatibilities = check_compatible(&a, &b);
+    assert_eq!(
+        incompatibilities,
+        vec![Incompatibility {
+            kind: ConflictKind::Incompatible,
+            severity: Severity::Error,
+            a: Some(Tag::AbiPcsR9Use(AbiPcsR9Use::Sb)),
+            b: Some(Tag::AbiPcsR9Use(AbiPcsR9Use::TlsPointer)),
+        },]
+    );
+
+    // Without the tag, R9 defaults to a general register
+    assert_eq!(
+        check_compatible(&a, &Attributes::default()),
+        vec![Incompatibility {
+            kind: ConflictKind::Incompatible,
+            severity: Severity::Error,
+            a: Some(Tag::AbiPcsR9Use(AbiPcsR9Use::Sb)),
+            b: None,
+        }]
+    );
+
+    // R9 is only known to be used as a general register if the tag is present
+    let a = Attributes {
+        abi_pcs_rw_data: Some(AbiPcsRwData::SbRel),
+        abi_pcs_r9_use: Some(AbiPcsR9Use::None),
+        ..Default::default()
+    };
+    assert_eq!(check_compatible(&a, &Attributes::default()), vec![]);
+    let b = Attributes {
+        abi_pcs_r9_use: Some(AbiPcsR9Use::V6),
+        ..Default::default()
+    };
+    assert_eq!(
+        check_compatible(&a, &b),
+        vec![Incompatibility {
+            kind: ConflictKind::R9Use,
+            severity: Severity::Error,
+            a: Some(Tag::AbiPcsRwData(AbiPcsRwData::SbRel)),
+            b: Some(Tag::AbiPcsR9Use(AbiPcsR9Use::V6)),
+        }]
+    );
+}
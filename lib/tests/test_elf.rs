@@ -0,0 +1,95 @@
+#![cfg(feature = "elf")]
+
+mod common;
+
+use std::io::Write;
+
+use arm_attr::{enums::*, error::ElfError, globals::*, read::Endian, BuildAttrs};
+use common::*;
+use flate2::{write::ZlibEncoder, Compression};
+
+#[rustfmt::skip]
+const RAW_LE: &[u8] = &[
+    b'A', // version
+    0x11, 0x00, 0x00, 0x00, // size
+    b'a', b'e', b'a', b'b', b'i', 0, // "aeabi" subsection
+    Tag_File, 0x07, 0x00, 0x00, 0x00, // file scope
+        Tag_CPU_arch, 4, // V5TE
+];
+
+#[rustfmt::skip]
+const RAW_BE: &[u8] = &[
+    b'A', // version
+    0x00, 0x00, 0x00, 0x11, // size
+    b'a', b'e', b'a', b'b', b'i', 0, // "aeabi" subsection
+    Tag_File, 0x00, 0x00, 0x00, 0x07, // file scope
+        Tag_CPU_arch, 4, // V5TE
+];
+
+fn attributes_section(data: &[u8]) -> Section<'_> {
+    Section {
+        name: ".ARM.attributes",
+        kind: SHT_ARM_ATTRIBUTES,
+        flags: 0,
+        data,
+    }
+}
+
+fn assert_cpu_arch(build_attrs: &BuildAttrs, endian: Endian) {
+    assert_eq!(build_attrs.endian(), endian);
+    let subsection = build_attrs.subsections().next().unwrap().unwrap();
+    let file = subsection.into_public_attributes().unwrap();
+    assert_eq!(file.attributes.cpu_arch, Some(CpuArch::V5TE));
+}
+
+#[test]
+fn test_from_elf() {
+    let text = Section {
+        name: ".text",
+        kind: SHT_PROGBITS,
+        flags: 0,
+        data: &[0; 8],
+    };
+
+    let elf = elf32(Endian::Little, EM_ARM, &[text, attributes_section(RAW_LE)]);
+    let build_attrs = BuildAttrs::from_elf(&elf).unwrap();
+    assert_cpu_arch(&build_attrs, Endian::Little);
+
+    let elf = elf32(Endian::Big, EM_ARM, &[attributes_section(RAW_BE)]);
+    let build_attrs = BuildAttrs::from_elf(&elf).unwrap();
+    assert_cpu_arch(&build_attrs, Endian::Big);
+}
+
+#[test]
+fn test_from_elf_compressed() {
+    let mut encoder = ZlibEncoder::new(vec![], Compression::default());
+    encoder.write_all(RAW_BE).unwrap();
+    let compressed = encoder.finish().unwrap();
+
+    let mut data = vec![];
+    data.extend_from_slice(&1u32.to_be_bytes()); // ch_type: ELFCOMPRESS_ZLIB
+    data.extend_from_slice(&(RAW_BE.len() as u32).to_be_bytes()); // ch_size
+    data.extend_from_slice(&1u32.to_be_bytes()); // ch_addralign
+    data.extend_from_slice(&compressed);
+
+    let section = Section {
+        flags: SHF_COMPRESSED,
+        ..attributes_section(&data)
+    };
+    let elf = elf32(Endian::Big, EM_ARM, &[section]);
+    let build_attrs = BuildAttrs::from_elf(&elf).unwrap();
+    assert_cpu_arch(&build_attrs, Endian::Big);
+}
+
+#[test]
+fn test_from_elf_missing_section() {
+    let section = Section {
+        name: ".ARM.attributes",
+        kind: SHT_PROGBITS,
+        flags: 0,
+        data: RAW_LE,
+    };
+    let elf = elf32(Endian::Little, EM_ARM, &[section]);
+    assert!(matches!(BuildAttrs::from_elf(&elf), Err(ElfError::NoAttributesSection)));
+    assert!(matches!(BuildAttrs::from_elf(RAW_LE), Err(ElfError::Object(_))));
+}
@@ -0,0 +1,142 @@
+mod common;
+
+use arm_attr::{
+    elf32::{find_attributes_section, replace_attributes_section},
+    enums::*,
+    error::{Elf32Error, ReadError},
+    globals::*,
+    read::Endian,
+    tag::Tag,
+    write::BuildAttrsWriter,
+    BuildAttrs,
+};
+use common::*;
+
+#[rustfmt::skip]
+const RAW: &[u8] = &[
+    b'A', // version
+    0x00, 0x00, 0x00, 0x11, // size
+    b'a', b'e', b'a', b'b', b'i', 0, // "aeabi" subsection
+    Tag_File, 0x00, 0x00, 0x00, 0x07, // file scope
+        Tag_CPU_arch, 4, // V5TE
+];
+
+fn sections(flags: u32) -> [Section<'static>; 3] {
+    [
+        Section {
+            name: ".text",
+            kind: SHT_PROGBITS,
+            flags: 0,
+            data: &[0; 8],
+        },
+        Section {
+            name: ".ARM.attributes.bak",
+            kind: SHT_ARM_ATTRIBUTES,
+            flags: 0,
+            data: &[0; 4],
+        },
+        Section {
+            name: ".ARM.attributes",
+            kind: SHT_ARM_ATTRIBUTES,
+            flags,
+            data: RAW,
+        },
+    ]
+}
+
+#[test]
+fn test_find_attributes_section() {
+    let elf = elf32(Endian::Big, EM_ARM, &sections(0));
+    let (data, endian) = find_attributes_section(&elf).unwrap();
+    assert_eq!(data, RAW);
+    assert_eq!(endian, Endian::Big);
+
+    let build_attrs = BuildAttrs::from_elf32(&elf).unwrap();
+    let subsection = build_attrs.subsections().next().unwrap().unwrap();
+    let file = subsection.into_public_attributes().unwrap();
+    assert_eq!(file.attributes.cpu_arch, Some(CpuArch::V5TE));
+}
+
+#[test]
+fn test_find_attributes_section_errors() {
+    let elf = elf32(Endian::Little, 3, &sections(0));
+    assert!(matches!(find_attributes_section(&elf), Err(Elf32Error::NotArm(3))));
+
+    let elf = elf32(Endian::Little, EM_ARM, &sections(SHF_COMPRESSED));
+    assert!(matches!(find_attributes_section(&elf), Err(Elf32Error::Compressed)));
+
+    let elf = elf32(Endian::Little, EM_ARM, &sections(0)[..2]);
+    assert!(matches!(find_attributes_section(&elf), Err(Elf32Error::NoAttributesSection)));
+
+    let mut elf = elf32(Endian::Little, EM_ARM, &sections(0));
+    elf[4] = 2; // ELFCLASS64
+    assert!(matches!(find_attributes_section(&elf), Err(Elf32Error::NotElf32)));
+
+    let elf = elf32(Endian::Little, EM_ARM, &sections(0));
+    assert!(matches!(
+        find_attributes_section(&elf[..100]),
+        Err(Elf32Error::Read(ReadError::OutOfBounds))
+    ));
+    assert!(matches!(
+        find_attributes_section(&elf[..20]),
+        Err(Elf32Error::Read(ReadError::Eof))
+    ));
+    assert!(matches!(find_attributes_section(RAW), Err(Elf32Error::NotElf)));
+}
+
+#[test]
+fn test_replace_attributes_section() {
+    let elf = elf32(Endian::Big, EM_ARM, &sections(0));
+
+    let mut writer = BuildAttrsWriter::new(Endian::Big);
+    writer.add_public_tags(&[
+        Tag::File { end_offset: 18 },
+        Tag::CpuArch(CpuArch::V7),
+        Tag::CpuRawName("cortex-a8"),
+    ]);
+    let larger = writer.finish();
+    assert!(larger.len() > RAW.len());
+    let grown = replace_attributes_section(&elf, &larger).unwrap();
+    assert_eq!(find_attributes_section(&grown).unwrap().0, larger);
+    assert_eq!(&grown[52..60], &elf[52..60], ".text was moved");
+    // The section header table after the section stays aligned
+    assert_eq!(u32::from_be_bytes(grown[32..36].try_into().unwrap()) % 4, 0);
+
+    // Smaller contents are written in place
+    let shrunk = replace_attributes_section(&grown, RAW).unwrap();
+    assert_eq!(find_attributes_section(&shrunk).unwrap().0, RAW);
+    assert_eq!(shrunk.len(), grown.len());
+}
+
+#[test]
+fn test_replace_attributes_section_relocated() {
+    let mut sections = Vec::from(sections(0));
+    sections.push(Section {
+        name: ".rel.ARM.attributes",
+        kind: SHT_REL,
+        flags: 0,
+        data: &[0; 8],
+    });
+    let mut elf = elf32(Endian::Little, EM_ARM, &sections);
+    // Point sh_info of the relocation section at .ARM.attributes
+    let shoff = u32::from_le_bytes(elf[32..36].try_into().unwrap()) as usize;
+    let info = shoff + 4 * 40 + 28;
+    elf[info..info + 4].copy_from_slice(&3u32.to_le_bytes());
+    assert!(matches!(replace_attributes_section(&elf, RAW), Err(Elf32Error::Relocated)));
+}
+
+#[test]
+fn test_replace_attributes_section_bad_program_headers() {
+    let mut elf = elf32(Endian::Little, EM_ARM, &sections(0));
+    let mut writer = BuildAttrsWriter::new(Endian::Little);
+    writer.add_public_tags(&[Tag::File { end_offset: 18 }, Tag::CpuRawName("cortex-a8")]);
+    let larger = writer.finish();
+    // One program header past the end of the file
+    elf[28..32].copy_from_slice(&0xffff_fff0u32.to_le_bytes());
+    elf[42..44].copy_from_slice(&32u16.to_le_bytes());
+    elf[44..46].copy_from_slice(&1u16.to_le_bytes());
+    assert!(matches!(
+        replace_attributes_section(&elf, &larger),
+        Err(Elf32Error::Read(ReadError::OutOfBounds))
+    ));
+}
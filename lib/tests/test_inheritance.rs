@@ -1,48 +1,187 @@
-use arm_attr::{enums::*, globals::*, read::Endian, BuildAttrs};
+use arm_attr::{enums::*, globals::*, read::Endian, tag::Tag, write::BuildAttrsWriter, BuildAttrs, ParseOptions};
+
+#[rustfmt::skip]
+const RAW: &[u8] = &[
+    b'A', // version
+    0x3f, 0x00, 0x00, 0x00, // size
+    b'a', b'e', b'a', b'b', b'i', 0, // "aeabi" subsection
+    Tag_File, 0x35, 0x00, 0x00, 0x00, // whole file
+        Tag_CPU_arch, 4, // V5TE
+        Tag_Section, 0x14, 0x00, 0x00, 0x00, 0xc8, 0x01, 42, 0, // sections 200 and 42
+            Tag_Symbol, 0x09, 0x00, 0x00, 0x00, 99, 0, // symbol 99
+                Tag_THUMB_ISA_use, 1, // Allowed16Bit
+            Tag_FP_arch, 1, // V1
+        Tag_Section, 0x1a, 0x00, 0x00, 0x00, 1, 0, // section 1
+            Tag_Symbol, 0x08, 0x00, 0x00, 0x00, 77, 66, 0, // symbols 77 and 66
+            Tag_Symbol, 0x09, 0x00, 0x00, 0x00, 88, 0, // symbol 88
+                Tag_THUMB_ISA_use, 1, // Allowed16Bit
+            Tag_nodefaults, 0,
+];
+
+/// Scopes with inherited attributes and tags out of order, with the attributes of each scope before its nested scopes like
+/// the writer lays them out
+#[rustfmt::skip]
+const RAW_INHERITED: &[u8] = &[
+    b'A', // version
+    0x43, 0x00, 0x00, 0x00, // size
+    b'a', b'e', b'a', b'b', b'i', 0, // "aeabi" subsection
+    Tag_File, 0x39, 0x00, 0x00, 0x00, // whole file
+        Tag_ARM_ISA_use, 1, // Allowed
+        Tag_CPU_arch, 3, // V5T
+        Tag_CPU_arch, 4, // V5TE, repeated
+        Tag_Section, 0x1a, 0x00, 0x00, 0x00, 1, 0, // section 1, inherits from the file
+            Tag_nodefaults, 0,
+            Tag_Symbol, 0x08, 0x00, 0x00, 0x00, 77, 66, 0, // symbols 77 and 66
+            Tag_Symbol, 0x09, 0x00, 0x00, 0x00, 88, 0, // symbol 88
+                Tag_THUMB_ISA_use, 1, // Allowed16Bit
+        Tag_Section, 0x14, 0x00, 0x00, 0x00, 0xc8, 0x01, 42, 0, // sections 200 and 42
+            Tag_FP_arch, 1, // V1
+            Tag_THUMB_ISA_use, 1, // Allowed16Bit
+            Tag_Symbol, 0x07, 0x00, 0x00, 0x00, 99, 0, // symbol 99, inherits from sections 200 and 42
+];
 
 #[test]
 fn test_inheritance() {
-    #[rustfmt::skip]
-    let raw = [
-        b'A', // version
-        0x3e, 0x00, 0x00, 0x00, // size
-        b'a', b'e', b'a', b'b', b'i', 0, // "aeabi" subsection
-        Tag_File, 0x34, 0x00, 0x00, 0x00, // whole file
-            Tag_CPU_arch, 4, // V5TE
-            Tag_Section, 0x13, 0x00, 0x00, 0x00, 53, 42, 0, // sections 53 and 42
-                Tag_Symbol, 0x09, 0x00, 0x00, 0x00, 99, 0, // symbol 99
-                    Tag_THUMB_ISA_use, 1, // Allowed16Bit
-                Tag_FP_arch, 1, // V1
-            Tag_Section, 0x1a, 0x00, 0x00, 0x00, 1, 0, // section 1
-                Tag_Symbol, 0x08, 0x00, 0x00, 0x00, 77, 66, 0, // symbols 77 and 66
-                Tag_Symbol, 0x09, 0x00, 0x00, 0x00, 88, 0, // symbol 88
-                    Tag_THUMB_ISA_use, 1, // Allowed16Bit
-                Tag_nodefaults, 0,
-    ];
-
-    let build_attrs = BuildAttrs::new(&raw, Endian::Little).unwrap();
+    let build_attrs = BuildAttrs::new(RAW, Endian::Little).unwrap();
     let mut subsections = build_attrs.subsections().map(|s| s.unwrap());
     let subsection = subsections.next().unwrap();
     assert!(subsection.is_aeabi());
 
     let file = subsection.into_public_attributes().unwrap();
     assert_eq!(file.attributes.cpu_arch, Some(CpuArch::V5TE));
+    assert_eq!(file.sections.len(), 2);
 
-    let section = file.sections.get([53, 42].as_ref()).unwrap();
+    let section = file.sections.get([200, 42].as_slice()).unwrap();
     assert_eq!(section.attributes.cpu_arch, None);
+    assert!(!section.inherited);
     assert_eq!(section.attributes.thumb_isa_use, None);
 
-    let symbol = section.symbols.get([99].as_ref()).unwrap();
+    let symbol = section.symbols.get([99].as_slice()).unwrap();
     assert_eq!(symbol.attributes.cpu_arch, None);
     assert_eq!(symbol.attributes.thumb_isa_use, Some(ThumbIsaUse::Allowed16Bit));
 
-    let section = file.sections.get([1].as_ref()).unwrap();
+    let section = file.sections.get([1].as_slice()).unwrap();
     assert_eq!(section.attributes.cpu_arch, Some(CpuArch::V5TE));
+    assert!(section.inherited);
 
-    let symbol = section.symbols.get([77, 66].as_ref()).unwrap();
+    let symbol = section.symbols.get([77, 66].as_slice()).unwrap();
     assert_eq!(symbol.attributes.cpu_arch, None);
+    assert!(!symbol.inherited);
 
-    let symbol = section.symbols.get([88].as_ref()).unwrap();
+    let symbol = section.symbols.get([88].as_slice()).unwrap();
     assert_eq!(symbol.attributes.cpu_arch, None);
     assert_eq!(symbol.attributes.thumb_isa_use, Some(ThumbIsaUse::Allowed16Bit));
 }
+
+#[test]
+fn test_inheritance_strict() {
+    let build_attrs = BuildAttrs::new(RAW, Endian::Little).unwrap();
+    let options = ParseOptions { strict: true };
+    let subsection = build_attrs.subsections_with_options(options).next().unwrap().unwrap();
+    let (_, warnings) = subsection.into_public_attributes_with_warnings().unwrap();
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn test_write_tags() {
+    let build_attrs = BuildAttrs::new(RAW, Endian::Little).unwrap();
+    let subsection = build_attrs.subsections().next().unwrap().unwrap();
+    let tags = subsection
+        .into_public_tag_iter()
+        .unwrap()
+        .map(|(_, tag)| tag)
+        .collect::<Vec<_>>();
+
+    let mut writer = BuildAttrsWriter::new(Endian::Little);
+    writer.add_public_tags(&tags);
+    assert_eq!(writer.finish(), RAW);
+}
+
+#[test]
+fn test_write_attributes() {
+    let build_attrs = BuildAttrs::new(RAW_INHERITED, Endian::Little).unwrap();
+    let subsection = build_attrs.subsections().next().unwrap().unwrap();
+    let file = subsection.into_public_attributes().unwrap();
+
+    let mut writer = BuildAttrsWriter::new(Endian::Big);
+    writer.add_public_attributes(&file);
+    let data = writer.finish();
+
+    let build_attrs = BuildAttrs::new(&data, Endian::Big).unwrap();
+    let mut subsections = build_attrs.subsections().map(|s| s.unwrap());
+    let subsection = subsections.next().unwrap();
+    assert!(subsection.is_aeabi());
+    assert!(subsections.next().is_none());
+
+    let written = subsection.into_public_attributes().unwrap();
+    assert_eq!(written, file);
+
+    // Changed attributes are written instead of the tags which no longer match them
+    let mut changed = file.clone();
+    changed.attributes.cpu_arch = Some(CpuArch::V7);
+    let mut writer = BuildAttrsWriter::new(Endian::Little);
+    writer.add_public_attributes(&changed);
+    let data = writer.finish();
+    let build_attrs = BuildAttrs::new(&data, Endian::Little).unwrap();
+    let subsection = build_attrs.subsections().next().unwrap().unwrap();
+    let written = subsection.into_public_attributes().unwrap();
+    assert_eq!(written.attributes, changed.attributes);
+}
+
+#[test]
+fn test_write_inherited_attributes() {
+    let build_attrs = BuildAttrs::new(RAW_INHERITED, Endian::Little).unwrap();
+    let subsection = build_attrs.subsections().next().unwrap().unwrap();
+    let file = subsection.into_public_attributes().unwrap();
+    let section = file.sections.get([1].as_slice()).unwrap();
+    assert!(section.inherited);
+    // Not inherited because of Tag_nodefaults in the section scope
+    assert!(!section.symbols.get([77, 66].as_slice()).unwrap().inherited);
+    let section = file.sections.get([200, 42].as_slice()).unwrap();
+    assert!(!section.inherited);
+    assert!(section.symbols.get([99].as_slice()).unwrap().inherited);
+
+    // Inherited attributes are not written, so the scopes inherit them again
+    let mut writer = BuildAttrsWriter::new(Endian::Little);
+    writer.add_public_attributes(&file);
+    assert_eq!(writer.finish(), RAW_INHERITED);
+}
+
+#[test]
+fn test_scope_tags() {
+    let build_attrs = BuildAttrs::new(RAW, Endian::Little).unwrap();
+    let subsection = build_attrs.subsections().next().unwrap().unwrap();
+    let mut tags = subsection.into_public_tag_iter().unwrap().filter(|(_, tag)| tag.is_scope());
+
+    assert_eq!(tags.next(), Some((0, Tag::File { end_offset: 53 })));
+    assert_eq!(
+        tags.next(),
+        Some((
+            7,
+            Tag::Section {
+                end_offset: 27,
+                sections: vec![200, 42]
+            }
+        ))
+    );
+    assert_eq!(
+        tags.next(),
+        Some((
+            16,
+            Tag::Symbol {
+                end_offset: 25,
+                symbols: vec![99]
+            }
+        ))
+    );
+    assert_eq!(
+        tags.next(),
+        Some((
+            27,
+            Tag::Section {
+                end_offset: 53,
+                sections: vec![1]
+            }
+        ))
+    );
+}
@@ -0,0 +1,149 @@
+use arm_attr::{
+    enums::*,
+    globals::*,
+    lint::{lint_attributes, lint_file, Diagnostic, Lint, LintScope},
+    read::Endian,
+    tag::Tag,
+    Attributes, BuildAttrs, File, SectionGroup,
+};
+
+#[test]
+fn test_lint_clean() {
+    let attributes = Attributes {
+        cpu_name: Some(CpuName::CortexM4),
+        cpu_arch: Some(CpuArch::V7EM),
+        cpu_arch_profile: Some(CpuArchProfile::Microcontroller),
+        thumb_isa_use: Some(ThumbIsaUse::Allowed),
+        fp_arch: Some(FpArch::V4Light),
+        abi_hardfp_use: Some(AbiHardFpUse::DerivedSingle),
+        ..Default::default()
+    };
+    assert_eq!(lint_attributes(&attributes), vec![]);
+
+    // Soft-float code uses FP hardware as implied by having no FP architecture
+    let soft_float = Attributes {
+        cpu_arch: Some(CpuArch::V7),
+        fp_arch: Some(FpArch::None),
+        abi_hardfp_use: Some(AbiHardFpUse::Implied),
+        ..Default::default()
+    };
+    assert_eq!(lint_attributes(&soft_float), vec![]);
+}
+
+#[test]
+fn test_lint_target() {
+    let attributes = Attributes {
+        cpu_name: Some(CpuName::CortexA9),
+        cpu_arch: Some(CpuArch::V7EM),
+        arm_isa_use: Some(ArmIsaUse::Allowed),
+        abi_hardfp_use: Some(AbiHardFpUse::DerivedSingle),
+        mve_arch: Some(MveArch::Int),
+        ..Default::default()
+    };
+    assert_eq!(
+        lint_attributes(&attributes),
+        vec![
+            Diagnostic {
+                lint: Lint::ArmIsaOnMProfile,
+                tags: vec![Tag::CpuArch(CpuArch::V7EM), Tag::ArmIsaUse(ArmIsaUse::Allowed)],
+            },
+            Diagnostic {
+                lint: Lint::HardFpUseWithoutFpArch,
+                tags: vec![Tag::AbiHardFpUse(AbiHardFpUse::DerivedSingle)],
+            },
+            Diagnostic {
+                lint: Lint::MveWithoutV8_1M,
+                tags: vec![Tag::MveArch(MveArch::Int), Tag::CpuArch(CpuArch::V7EM)],
+            },
+            Diagnostic {
+                lint: Lint::CpuNameMismatch,
+                tags: vec![Tag::CpuName(CpuName::CortexA9), Tag::CpuArch(CpuArch::V7EM)],
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_lint_profile() {
+    // ARMv7 is M-profile by Tag_CPU_arch_profile
+    let attributes = Attributes {
+        cpu_arch: Some(CpuArch::V7),
+        cpu_arch_profile: Some(CpuArchProfile::Microcontroller),
+        arm_isa_use: Some(ArmIsaUse::Allowed),
+        mve_arch: Some(MveArch::None),
+        ..Default::default()
+    };
+    let diagnostics = lint_attributes(&attributes);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].lint, Lint::ArmIsaOnMProfile);
+    assert_eq!(diagnostics[0].lint.code(), "L001");
+}
+
+#[test]
+fn test_lint_file() {
+    let mut file = File {
+        attributes: Attributes {
+            cpu_arch: Some(CpuArch::V5TE),
+            t2ee_use: Some(T2EeUse::None),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let section = SectionGroup {
+        attributes: file.attributes.clone(),
+        inherited: true,
+        ..Default::default()
+    };
+    file.sections.insert(vec![1], section);
+
+    let deprecated_tag = Diagnostic {
+        lint: Lint::DeprecatedTag,
+        tags: vec![Tag::T2EeUse(T2EeUse::None)],
+    };
+    let deprecated_scope = Diagnostic {
+        lint: Lint::DeprecatedScope,
+        tags: vec![],
+    };
+    // The inherited attributes of the section scope are only linted once, in the file scope
+    assert_eq!(
+        lint_file(&file),
+        vec![
+            (LintScope::File, deprecated_tag),
+            (LintScope::Section(vec![1]), deprecated_scope),
+        ]
+    );
+}
+
+#[test]
+fn test_lint_repeated_tags() {
+    #[rustfmt::skip]
+    let raw = [
+        b'A', // version
+        0x17, 0x00, 0x00, 0x00, // size
+        b'a', b'e', b'a', b'b', b'i', 0, // "aeabi" subsection
+        Tag_File, 0x0d, 0x00, 0x00, 0x00, // file scope
+            Tag_CPU_arch, 10, // V7
+            Tag_ABI_enum_size, 2, // Always32
+            Tag_CPU_arch, 13, // V7EM, conflicting
+            Tag_ABI_enum_size, 2, // Always32, the same value
+    ];
+    let build_attrs = BuildAttrs::new(&raw, Endian::Little).unwrap();
+    let subsection = build_attrs.subsections().next().unwrap().unwrap();
+    let file = subsection.into_public_attributes().unwrap();
+    assert_eq!(file.attributes.cpu_arch, Some(CpuArch::V7EM));
+    assert_eq!(
+        file.tags,
+        vec![
+            (5, Tag::CpuArch(CpuArch::V7)),
+            (7, Tag::AbiEnumSize(AbiEnumSize::Always32)),
+            (9, Tag::CpuArch(CpuArch::V7EM)),
+            (11, Tag::AbiEnumSize(AbiEnumSize::Always32)),
+        ]
+    );
+
+    let diagnostic = Diagnostic {
+        lint: Lint::ConflictingRepeatedTag,
+        tags: vec![Tag::CpuArch(CpuArch::V7), Tag::CpuArch(CpuArch::V7EM)],
+    };
+    assert_eq!(lint_file(&file), vec![(LintScope::File, diagnostic)]);
+}
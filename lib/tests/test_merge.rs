@@ -0,0 +1,198 @@
+use arm_attr::{
+    enums::*,
+    merge::{merge, Conflict, ConflictKind},
+    tag::{Tag, UnknownValue},
+    Attributes,
+};
+
+#[test]
+fn test_merge_target() {
+    let a = Attributes {
+        cpu_name: Some(CpuName::Arm946ES),
+        cpu_arch: Some(CpuArch::V5TE),
+        fp_arch: Some(FpArch::V3Light),
+        thumb_isa_use: Some(ThumbIsaUse::Allowed16Bit),
+        ..Default::default()
+    };
+    let b = Attributes {
+        cpu_name: Some(CpuName::CortexA8),
+        cpu_arch: Some(CpuArch::V7),
+        cpu_arch_profile: Some(CpuArchProfile::Classic),
+        fp_arch: Some(FpArch::V4Light),
+        ..Default::default()
+    };
+    let c = Attributes {
+        cpu_arch: Some(CpuArch::V6M),
+        cpu_arch_profile: Some(CpuArchProfile::Application),
+        fp_arch: Some(FpArch::V3),
+        ..Default::default()
+    };
+
+    let merged = merge(&[&a, &b, &c]);
+    assert_eq!(merged.conflicts, vec![]);
+    assert_eq!(merged.attributes.cpu_name, Some(CpuName::CortexA8));
+    assert_eq!(merged.attributes.cpu_arch, Some(CpuArch::V7));
+    assert_eq!(merged.attributes.cpu_arch_profile, Some(CpuArchProfile::Application));
+    assert_eq!(merged.attributes.fp_arch, Some(FpArch::V4));
+    assert_eq!(merged.attributes.thumb_isa_use, Some(ThumbIsaUse::Allowed16Bit));
+    assert_eq!(merged.attributes.arm_isa_use, None);
+}
+
+#[test]
+fn test_merge_pcs() {
+    let a = Attributes {
+        abi_pcs_wchar_t: Some(AbiPcsWcharT::Size4),
+        abi_enum_size: Some(AbiEnumSize::Visible32),
+        ..Default::default()
+    };
+    let b = Attributes {
+        abi_pcs_wchar_t: Some(AbiPcsWcharT::None),
+        abi_enum_size: Some(AbiEnumSize::SmallestSize),
+        ..Default::default()
+    };
+    let c = Attributes {
+        abi_pcs_wchar_t: Some(AbiPcsWcharT::Size2),
+        abi_enum_size: Some(AbiEnumSize::Always32),
+        ..Default::default()
+    };
+
+    let merged = merge(&[&a, &b]);
+    assert_eq!(merged.conflicts, vec![]);
+    assert_eq!(merged.attributes.abi_pcs_wchar_t, Some(AbiPcsWcharT::Size4));
+    assert_eq!(merged.attributes.abi_enum_size, Some(AbiEnumSize::SmallestSize));
+
+    let merged = merge(&[&a, &b, &c]);
+    assert_eq!(
+        merged.conflicts,
+        vec![
+            Conflict {
+                kind: ConflictKind::Incompatible,
+                attributes: vec![
+                    (0, Some(Tag::AbiPcsWcharT(AbiPcsWcharT::Size4))),
+                    (2, Some(Tag::AbiPcsWcharT(AbiPcsWcharT::Size2)))
+                ],
+            },
+            Conflict {
+                kind: ConflictKind::Incompatible,
+                attributes: vec![
+                    (1, Some(Tag::AbiEnumSize(AbiEnumSize::SmallestSize))),
+                    (2, Some(Tag::AbiEnumSize(AbiEnumSize::Always32)))
+                ],
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_merge_vfp_args() {
+    let hard = Attributes {
+        abi_vfp_args: Some(AbiVfpArgs::Vfp),
+        abi_fp_number_model: Some(AbiFpNumberModel::All),
+        ..Default::default()
+    };
+    let soft = Attributes {
+        abi_fp_number_model: Some(AbiFpNumberModel::All),
+        ..Default::default()
+    };
+    let no_fp = Attributes {
+        abi_vfp_args: Some(AbiVfpArgs::Base),
+        ..Default::default()
+    };
+    let compatible = Attributes {
+        abi_vfp_args: Some(AbiVfpArgs::BaseVfp),
+        abi_fp_number_model: Some(AbiFpNumberModel::All),
+        ..Default::default()
+    };
+
+    let merged = merge(&[&no_fp, &compatible, &hard]);
+    assert_eq!(merged.conflicts, vec![]);
+    assert_eq!(merged.attributes.abi_vfp_args, Some(AbiVfpArgs::Vfp));
+
+    let merged = merge(&[&hard, &soft]);
+    assert_eq!(
+        merged.conflicts,
+        vec![Conflict {
+            kind: ConflictKind::Incompatible,
+            attributes: vec![(0, Some(Tag::AbiVfpArgs(AbiVfpArgs::Vfp))), (1, None)],
+        }]
+    );
+}
+
+#[test]
+fn test_merge_alignment() {
+    let needs = Attributes {
+        abi_align_needed: Some(AbiAlignNeeded::Align8),
+        abi_align_preserved: Some(AbiAlignPreserved::Align8),
+        ..Default::default()
+    };
+    let preserves = Attributes {
+        abi_align_needed: Some(AbiAlignNeeded::Align4),
+        abi_align_preserved: Some(AbiAlignPreserved::Align2n(4)),
+        ..Default::default()
+    };
+    let no_preserve = Attributes {
+        abi_align_needed: Some(AbiAlignNeeded::None),
+        ..Default::default()
+    };
+
+    let merged = merge(&[&needs, &preserves]);
+    assert_eq!(merged.conflicts, vec![]);
+    assert_eq!(merged.attributes.abi_align_needed, Some(AbiAlignNeeded::Align8));
+    assert_eq!(merged.attributes.abi_align_preserved, Some(AbiAlignPreserved::Align8));
+
+    let merged = merge(&[&needs, &no_preserve]);
+    assert_eq!(
+        merged.conflicts,
+        vec![Conflict {
+            kind: ConflictKind::AlignmentNotPreserved,
+            attributes: vec![(0, Some(Tag::AbiAlignNeeded(AbiAlignNeeded::Align8))), (1, None)],
+        }]
+    );
+    assert_eq!(merged.attributes.abi_align_preserved, Some(AbiAlignPreserved::None));
+}
+
+#[test]
+fn test_merge_unknown() {
+    let mut a = Attributes::default();
+    a.unknown.insert(40, UnknownValue::Uleb128(1));
+    a.unknown.insert(80, UnknownValue::Uleb128(2));
+    a.unknown.insert(82, UnknownValue::Uleb128(3));
+    let mut b = Attributes::default();
+    b.unknown.insert(80, UnknownValue::Uleb128(2));
+    b.unknown.insert(82, UnknownValue::Uleb128(4));
+
+    let merged = merge(&[&a, &b]);
+    assert_eq!(
+        merged.conflicts,
+        vec![Conflict {
+            kind: ConflictKind::UnknownMandatoryTag,
+            attributes: vec![(
+                0,
+                Some(Tag::Unknown {
+                    tag: 40,
+                    value: UnknownValue::Uleb128(1)
+                })
+            )],
+        }]
+    );
+    assert_eq!(merged.attributes.unknown.len(), 1);
+    assert_eq!(merged.attributes.unknown.get(&80), Some(&UnknownValue::Uleb128(2)));
+}
+
+#[test]
+fn test_merge_unknown_arch() {
+    let a = Attributes {
+        cpu_arch: Some(CpuArch::V7),
+        ..Default::default()
+    };
+    let b = Attributes {
+        cpu_arch: Some(CpuArch::Unknown(u32::MAX)),
+        ..Default::default()
+    };
+
+    let merged = merge(&[&a, &b]);
+    assert_eq!(merged.conflicts, vec![]);
+    assert_eq!(merged.attributes.cpu_arch, Some(CpuArch::Unknown(u32::MAX)));
+    let merged = merge(&[&b, &a]);
+    assert_eq!(merged.attributes.cpu_arch, Some(CpuArch::Unknown(u32::MAX)));
+}
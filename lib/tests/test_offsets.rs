@@ -0,0 +1,92 @@
+use arm_attr::{enums::*, error::*, globals::*, read::Endian, tag::Tag, BuildAttrs};
+
+#[test]
+fn test_tag_offsets() {
+    #[rustfmt::skip]
+    let raw = [
+        b'A', // version
+        0x16, 0x00, 0x00, 0x00, // size
+        b'a', b'e', b'a', b'b', b'i', 0, // "aeabi" subsection
+        Tag_File, // file scope
+        0x0c, 0x00, 0x00, 0x00, // scope size
+        Tag_CPU_arch, 10, // V7
+        Tag_CPU_name, b'A', 0xff, b'B', 0, // invalid UTF-8
+    ];
+
+    let build_attrs = BuildAttrs::new(&raw, Endian::Little).unwrap();
+    assert_eq!(build_attrs.data(), raw);
+    let mut subsections = build_attrs.subsections();
+    assert_eq!(subsections.offset(), 1);
+    let subsection = subsections.next().unwrap().unwrap();
+    assert_eq!(subsections.offset(), raw.len() as u32);
+
+    let mut tags = subsection.into_public_tag_iter().unwrap();
+    assert_eq!(tags.next(), Some((0, Tag::File { end_offset: 12 })));
+    assert_eq!(tags.next(), Some((5, Tag::CpuArch(CpuArch::V7))));
+    assert_eq!(tags.offset(), 7);
+    assert_eq!(tags.next(), None);
+    // Stopped at the invalid byte
+    assert_eq!(tags.offset(), 9);
+}
+
+#[test]
+fn test_tag_offsets_overflow() {
+    #[rustfmt::skip]
+    let raw = [
+        b'A', // version
+        0x17, 0x00, 0x00, 0x00, // size
+        b'a', b'e', b'a', b'b', b'i', 0, // "aeabi" subsection
+        Tag_File, // file scope
+        0x0d, 0x00, 0x00, 0x00, // scope size
+        Tag_CPU_arch, 0xff, 0xff, 0xff, 0xff, 0x10, // 0x10fffffff, too large for u32
+        Tag_ABI_enum_size, 2, // Always32
+    ];
+
+    let build_attrs = BuildAttrs::new(&raw, Endian::Little).unwrap();
+    let subsection = build_attrs.subsections().next().unwrap().unwrap();
+    let mut tags = subsection.into_public_tag_iter().unwrap();
+    tags.next().unwrap();
+    assert_eq!(tags.next(), None);
+    // Stopped at the start of the value
+    assert_eq!(tags.offset(), 6);
+}
+
+#[test]
+fn test_subsection_length_errors() {
+    #[rustfmt::skip]
+    let too_short = [
+        b'A', // version
+        0x03, 0x00, 0x00, 0x00, // size, shorter than the size and name
+        b'a', b'e', b'a', b'b', b'i', 0, // "aeabi" subsection
+    ];
+    let build_attrs = BuildAttrs::new(&too_short, Endian::Little).unwrap();
+    let mut subsections = build_attrs.subsections();
+    let Some(Err(SubsectionError::Read { location, error })) = subsections.next() else {
+        panic!("expected a read error")
+    };
+    assert!(matches!(error, ReadError::OutOfBounds));
+    // The start of the subsection header
+    assert_eq!(location.offset, 1);
+    assert_eq!(location.vendor.as_deref(), Some("aeabi"));
+    // The position of the next subsection is not known
+    assert!(subsections.next().is_none());
+
+    #[rustfmt::skip]
+    let too_long = [
+        b'A', // version
+        0x40, 0x00, 0x00, 0x00, // size, longer than the section
+        b'a', b'e', b'a', b'b', b'i', 0, // "aeabi" subsection
+        0x06, 0x00, 0x00, 0x00, b'x', 0, // subsection data which looks like a subsection header
+    ];
+    let build_attrs = BuildAttrs::new(&too_long, Endian::Little).unwrap();
+    let mut subsections = build_attrs.subsections();
+    let Some(Err(SubsectionError::Read { location, error })) = subsections.next() else {
+        panic!("expected a read error")
+    };
+    assert!(matches!(error, ReadError::OutOfBounds));
+    // The start of the subsection header
+    assert_eq!(location.offset, 1);
+    assert_eq!(location.vendor.as_deref(), Some("aeabi"));
+    // The position of the next subsection is not known
+    assert!(subsections.next().is_none());
+}
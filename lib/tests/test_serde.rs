@@ -0,0 +1,87 @@
+#![cfg(feature = "serde")]
+
+use arm_attr::{
+    enums::*,
+    globals::*,
+    read::Endian,
+    tag::{Tag, UnknownValue},
+    Attributes, BuildAttrs, File,
+};
+use serde_json::json;
+
+#[rustfmt::skip]
+const RAW: &[u8] = &[
+    b'A', // version
+    0x3b, 0x00, 0x00, 0x00, // size
+    b'a', b'e', b'a', b'b', b'i', 0, // "aeabi" subsection
+    Tag_File, 0x31, 0x00, 0x00, 0x00, // whole file
+        Tag_CPU_name, b'A', b'R', b'M', b'9', b'4', b'6', b'E', b'-', b'S', 0, // ARM946E-S
+        Tag_CPU_arch, 0x80, 0x01, // Unknown(128)
+        Tag_compatibility, 42, b'f', b'o', b'o', 0, // Private { flag: 42, vendor: "foo" }
+        81, b'h', b'i', 0, // odd tag, string value "hi"
+    Tag_Section, 0x14, 0x00, 0x00, 0x00, 0xc8, 0x01, 42, 0, // sections 200 and 42
+        Tag_Symbol, 0x09, 0x00, 0x00, 0x00, 99, 0, // symbol 99
+            Tag_THUMB_ISA_use, 1, // Allowed16Bit
+        Tag_FP_arch, 1, // V1
+];
+
+#[test]
+fn test_serde_file() {
+    let build_attrs = BuildAttrs::new(RAW, Endian::Little).unwrap();
+    let subsection = build_attrs.subsections().next().unwrap().unwrap();
+    let file = subsection.into_public_attributes().unwrap();
+
+    let value = serde_json::to_value(&file).unwrap();
+    assert_eq!(
+        value["attributes"]["cpu_name"],
+        json!({ "value": "ARM946E-S", "name": "Arm946ES" })
+    );
+    assert_eq!(value["attributes"]["cpu_arch"], json!({ "value": 128, "name": "Unknown" }));
+    assert_eq!(
+        value["attributes"]["compat"],
+        json!({ "value": 42, "name": "Private", "vendor": "foo" })
+    );
+    assert_eq!(value["attributes"]["unknown"], json!({ "81": { "String": "hi" } }));
+    assert_eq!(value["sections"][0][0], json!([200, 42]));
+    assert_eq!(
+        value["sections"][0][1]["symbols"][0][1]["attributes"]["thumb_isa_use"],
+        json!({ "value": 1, "name": "Allowed16Bit" })
+    );
+
+    let json = serde_json::to_string(&file).unwrap();
+    let deserialized: File = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, file);
+}
+
+#[test]
+fn test_serde_round_trip() {
+    let attributes = Attributes {
+        cpu_name: Some(CpuName::Other("custom")),
+        cpu_arch_profile: Some(CpuArchProfile::Unknown(0x10041)),
+        abi_align_needed: Some(AbiAlignNeeded::Align2n(4)),
+        compat: Some(Compat::ByToolchain("gcc")),
+        also_compat_with: Some(AlsoCompatWith::Reserved(Box::new(Tag::CpuName(CpuName::Arm7Tdmi)))),
+        conform: Some(Conform::None),
+        ..Default::default()
+    };
+    let json = serde_json::to_string(&attributes).unwrap();
+    let deserialized: Attributes = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, attributes);
+
+    let tags = vec![
+        Tag::File { end_offset: 10 },
+        Tag::Section {
+            end_offset: 10,
+            sections: vec![1, 200],
+        },
+        Tag::Conform(Conform::Unknown("2024Q1")),
+        Tag::AbiVfpArgs(AbiVfpArgs::Unknown(7)),
+        Tag::Unknown {
+            tag: 256,
+            value: UnknownValue::Uleb128(7),
+        },
+    ];
+    let json = serde_json::to_string(&tags).unwrap();
+    let deserialized: Vec<Tag> = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, tags);
+}
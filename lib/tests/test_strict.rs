@@ -0,0 +1,205 @@
+use arm_attr::{enums::*, error::*, globals::*, read::Endian, BuildAttrs, ParseOptions};
+
+const STRICT: ParseOptions = ParseOptions { strict: true };
+
+/// Returns the violation which fails parsing the public attributes of the first subsection strictly.
+fn strict_violation(raw: &[u8]) -> (Location, Violation) {
+    let build_attrs = BuildAttrs::new(raw, Endian::Little).unwrap();
+    let subsection = build_attrs.subsections_with_options(STRICT).next().unwrap().unwrap();
+    match subsection.into_public_attributes() {
+        Err(PublicAttrsError::Invalid { location, violation }) => (location, violation),
+        result => panic!("expected a violation, got {result:?}"),
+    }
+}
+
+#[test]
+fn test_trailing_bytes_after_subsection() {
+    #[rustfmt::skip]
+    let raw = [
+        b'A', // version
+        0x0f, 0x00, 0x00, 0x00, // size
+        b'a', b'e', b'a', b'b', b'i', 0, // "aeabi" subsection
+        Tag_File, 0x05, 0x00, 0x00, 0x00, // file scope
+        0x01, 0x02, // too short for a subsection header
+    ];
+    let build_attrs = BuildAttrs::new(&raw, Endian::Little).unwrap();
+    let mut subsections = build_attrs.subsections();
+    assert!(subsections.next().unwrap().is_ok());
+    assert!(subsections.next().is_none());
+    assert_eq!(subsections.warnings().len(), 1);
+    assert_eq!(subsections.warnings()[0].violation, Violation::TrailingBytes);
+    assert_eq!(subsections.warnings()[0].location.offset, 16);
+
+    let mut subsections = build_attrs.subsections_with_options(STRICT);
+    assert!(subsections.next().unwrap().is_ok());
+    let Some(Err(SubsectionError::Invalid { location, violation })) = subsections.next() else {
+        panic!("expected a violation")
+    };
+    assert_eq!(violation, Violation::TrailingBytes);
+    assert_eq!(location.offset, 16);
+    assert!(subsections.next().is_none());
+}
+
+#[test]
+fn test_empty_subsection() {
+    #[rustfmt::skip]
+    let raw = [
+        b'A', // version
+        0x0a, 0x00, 0x00, 0x00, // size
+        b'a', b'e', b'a', b'b', b'i', 0, // "aeabi" subsection without tags
+    ];
+    let build_attrs = BuildAttrs::new(&raw, Endian::Little).unwrap();
+    let mut subsections = build_attrs.subsections();
+    assert!(subsections.next().unwrap().is_ok());
+    assert_eq!(subsections.warnings()[0].violation, Violation::EmptySubsection);
+
+    let mut subsections = build_attrs.subsections_with_options(STRICT);
+    let Some(Err(SubsectionError::Invalid { location, violation })) = subsections.next() else {
+        panic!("expected a violation")
+    };
+    assert_eq!(violation, Violation::EmptySubsection);
+    assert_eq!(location.offset, 1);
+    assert_eq!(location.vendor.as_deref(), Some("aeabi"));
+}
+
+#[test]
+fn test_missing_string_terminator() {
+    #[rustfmt::skip]
+    let raw = [
+        b'A', // version
+        0x13, 0x00, 0x00, 0x00, // size
+        b'a', b'e', b'a', b'b', b'i', 0, // "aeabi" subsection
+        Tag_File, 0x09, 0x00, 0x00, 0x00, // file scope
+            Tag_CPU_name, b'C', b'M', b'4', // no null
+    ];
+    let build_attrs = BuildAttrs::new(&raw, Endian::Little).unwrap();
+    let subsection = build_attrs.subsections().next().unwrap().unwrap();
+    let (file, warnings) = subsection.into_public_attributes_with_warnings().unwrap();
+    assert_eq!(file.attributes.cpu_name, Some(CpuName::Other("CM4")));
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].violation, Violation::MissingTerminator);
+
+    let (location, violation) = strict_violation(&raw);
+    assert_eq!(violation, Violation::MissingTerminator);
+    assert_eq!(location.offset, 16);
+    assert_eq!(location.tag, Some(Tag_CPU_name.into()));
+}
+
+#[test]
+fn test_missing_list_terminator() {
+    #[rustfmt::skip]
+    let raw = [
+        b'A', // version
+        0x15, 0x00, 0x00, 0x00, // size
+        b'a', b'e', b'a', b'b', b'i', 0, // "aeabi" subsection
+        Tag_File, 0x0b, 0x00, 0x00, 0x00, // file scope
+        Tag_Section, 0x06, 0x00, 0x00, 0x00, 1, // no zero after the section indices
+    ];
+    let build_attrs = BuildAttrs::new(&raw, Endian::Little).unwrap();
+    let subsection = build_attrs.subsections().next().unwrap().unwrap();
+    let (file, warnings) = subsection.into_public_attributes_with_warnings().unwrap();
+    assert!(file.sections.is_empty());
+    assert_eq!(warnings[0].violation, Violation::MissingTerminator);
+
+    let (location, violation) = strict_violation(&raw);
+    assert_eq!(violation, Violation::MissingTerminator);
+    assert_eq!(location.offset, 16);
+    assert_eq!(location.tag, Some(Tag_Section.into()));
+}
+
+#[test]
+fn test_attributes_after_scope() {
+    #[rustfmt::skip]
+    let raw = [
+        b'A', // version
+        0x1a, 0x00, 0x00, 0x00, // size
+        b'a', b'e', b'a', b'b', b'i', 0, // "aeabi" subsection
+        Tag_File, 0x10, 0x00, 0x00, 0x00, // file scope
+        Tag_Section, 0x09, 0x00, 0x00, 0x00, 1, 0, // section scope
+            Tag_CPU_arch, 10, // V7
+        Tag_ABI_enum_size, 2, // file attribute after the section scope
+    ];
+    let build_attrs = BuildAttrs::new(&raw, Endian::Little).unwrap();
+    let subsection = build_attrs.subsections().next().unwrap().unwrap();
+    let (file, warnings) = subsection.into_public_attributes_with_warnings().unwrap();
+    assert_eq!(file.attributes.abi_enum_size, Some(AbiEnumSize::Always32));
+    assert!(warnings.is_empty());
+
+    let subsection = build_attrs.subsections_with_options(STRICT).next().unwrap().unwrap();
+    let file = subsection.into_public_attributes().unwrap();
+    assert_eq!(file.attributes.abi_enum_size, Some(AbiEnumSize::Always32));
+    assert_eq!(file.sections[&vec![1]].attributes.cpu_arch, Some(CpuArch::V7));
+}
+
+#[test]
+fn test_overlapping_scopes() {
+    #[rustfmt::skip]
+    let raw = [
+        b'A', // version
+        0x24, 0x00, 0x00, 0x00, // size
+        b'a', b'e', b'a', b'b', b'i', 0, // "aeabi" subsection
+        Tag_File, 0x1a, 0x00, 0x00, 0x00, // file scope
+        Tag_Section, 0x15, 0x00, 0x00, 0x00, 1, 0, // section scope
+            Tag_Symbol, 0x09, 0x00, 0x00, 0x00, 1, 0, // symbol scope, which ends after the next one starts
+            Tag_Symbol, 0x07, 0x00, 0x00, 0x00, 2, 0, // symbol scope
+    ];
+    let build_attrs = BuildAttrs::new(&raw, Endian::Little).unwrap();
+    let subsection = build_attrs.subsections().next().unwrap().unwrap();
+    let (file, warnings) = subsection.into_public_attributes_with_warnings().unwrap();
+    assert_eq!(file.sections[&vec![1]].symbols.len(), 2);
+    assert_eq!(warnings[0].violation, Violation::OverlappingScopes);
+
+    let (location, violation) = strict_violation(&raw);
+    assert_eq!(violation, Violation::OverlappingScopes);
+    assert_eq!(location.offset, 30);
+    assert_eq!(location.scope, Some(ErrorScope::Symbol(vec![1])));
+}
+
+#[test]
+fn test_scope_length_overflow() {
+    #[rustfmt::skip]
+    let raw = [
+        b'A', // version
+        0x18, 0x00, 0x00, 0x00, // size
+        b'a', b'e', b'a', b'b', b'i', 0, // "aeabi" subsection
+        Tag_File, 0x0e, 0x00, 0x00, 0x00, // file scope
+        Tag_Section, 0x20, 0x00, 0x00, 0x00, 1, 0, // section scope, longer than the file scope
+            Tag_CPU_arch, 10, // V7
+    ];
+    let build_attrs = BuildAttrs::new(&raw, Endian::Little).unwrap();
+    let subsection = build_attrs.subsections().next().unwrap().unwrap();
+    let (file, warnings) = subsection.into_public_attributes_with_warnings().unwrap();
+    assert_eq!(file.sections[&vec![1]].attributes.cpu_arch, Some(CpuArch::V7));
+    assert_eq!(warnings[0].violation, Violation::LengthOverflow);
+
+    let (location, violation) = strict_violation(&raw);
+    assert_eq!(violation, Violation::LengthOverflow);
+    assert_eq!(location.offset, 16);
+}
+
+#[test]
+fn test_truncated_tag() {
+    #[rustfmt::skip]
+    let raw = [
+        b'A', // version
+        0x12, 0x00, 0x00, 0x00, // size
+        b'a', b'e', b'a', b'b', b'i', 0, // "aeabi" subsection
+        Tag_File, 0x08, 0x00, 0x00, 0x00, // file scope
+            Tag_ABI_enum_size, 2, // Always32
+            Tag_CPU_arch, // no value
+    ];
+    let build_attrs = BuildAttrs::new(&raw, Endian::Little).unwrap();
+    let subsection = build_attrs.subsections().next().unwrap().unwrap();
+    let (file, warnings) = subsection.into_public_attributes_with_warnings().unwrap();
+    assert_eq!(file.attributes.abi_enum_size, Some(AbiEnumSize::Always32));
+    assert_eq!(file.attributes.cpu_arch, None);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].violation, Violation::Truncated);
+    assert_eq!(warnings[0].location.offset, 18);
+    assert_eq!(warnings[0].location.tag, Some(Tag_CPU_arch.into()));
+
+    let (location, violation) = strict_violation(&raw);
+    assert_eq!(violation, Violation::Truncated);
+    assert_eq!(location.offset, 18);
+    assert_eq!(location.tag, Some(Tag_CPU_arch.into()));
+}
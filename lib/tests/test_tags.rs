@@ -1,4 +1,11 @@
-use arm_attr::{enums::*, globals::*, read::Endian, tag::Tag, BuildAttrs};
+use arm_attr::{
+    enums::*,
+    globals::*,
+    read::Endian,
+    tag::{Tag, UnknownValue},
+    write::BuildAttrsWriter,
+    Attributes, BuildAttrs,
+};
 
 macro_rules! assert_tag {
     ($attr:expr, $tag:expr) => {
@@ -6,71 +13,71 @@ macro_rules! assert_tag {
     };
 }
 
+#[rustfmt::skip]
+const RAW: &[u8] = &[
+    b'A', // version
+    0x9f, 0x00, 0x00, 0x00, // size
+    b'a', b'e', b'a', b'b', b'i', 0, // "aeabi" subsection
+    Tag_File, // file scope
+    0x95, 0x00, 0x00, 0x00, // scope size
+    Tag_CPU_raw_name, b'V', b'5', b'T', b'E', 0, // "V5TE"
+    Tag_CPU_name, b'A', b'R', b'M', b'9', b'4', b'6', b'E', b'-', b'S', 0, // ARM946E-S
+    Tag_CPU_arch, 4, // V5TE
+    Tag_CPU_arch_profile, 0, // NotApplicable
+    Tag_ARM_ISA_use, 1, // Allowed
+    Tag_THUMB_ISA_use, 1, // Allowed16Bit
+    Tag_FP_arch, 1, // V1
+    Tag_WMMX_arch, 2, // V2
+    Tag_Advanced_SIMD_arch, 4, // V8_1A
+    Tag_PCS_config, 3, // LinuxDso
+    Tag_ABI_PCS_R9_use, 1, // Sb
+    Tag_ABI_PCS_RW_data, 2, // SbRel
+    Tag_ABI_PCS_RO_data, 2, // None
+    Tag_ABI_PCS_GOT_use, 1, // Direct
+    Tag_ABI_PCS_wchar_t, 4, // Size4
+    Tag_ABI_FP_rounding, 0, // Nearest
+    Tag_ABI_FP_denormal, 2, // PreserveSign
+    Tag_ABI_FP_exceptions, 1, // CheckInexact
+    Tag_ABI_FP_user_exceptions, 1, // Enabled
+    Tag_ABI_FP_number_model, 2, // InfNaN
+    Tag_ABI_align_needed, 2, // Align4
+    Tag_ABI_align_needed, 4, // Align2n(4)
+    Tag_ABI_align_needed, 12, // Align2n(12)
+    Tag_ABI_align_preserved, 1, // Align8
+    Tag_ABI_align_preserved, 4, // Align2n(4)
+    Tag_ABI_align_preserved, 12, // Align2n(12)
+    Tag_ABI_enum_size, 1, // SmallestSize
+    Tag_ABI_HardFP_use, 0, // Implied
+    Tag_ABI_VFP_args, 2, // Toolchain
+    Tag_ABI_WMMX_args, 0, // Base
+    Tag_ABI_optimization_goals, 2, // OptimizeSpeed
+    Tag_ABI_FP_optimization_goals, 5, // FavorAccuracy
+    Tag_compatibility, 0, // Always
+    Tag_compatibility, 1, b'X', b'Y', b'Z', 0, // ByToolchain("XYZ")
+    Tag_compatibility, 42, b'f', b'o', b'o', 0, // Private { flag: 42, vendor: "foo" }
+    Tag_CPU_unaligned_access, 1, // Allowed
+    Tag_FP_HP_extension, 0, // IfExists
+    Tag_ABI_FP_16bit_format, 2, // Alternative
+    Tag_MPextension_use, 1, // Allowed
+    Tag_DIV_use, 1, // None
+    Tag_DSP_extension, 0, // IfExists
+    Tag_MVE_arch, 2, // IntFloat
+    Tag_PAC_extension, 1, // OnlyNopSpace
+    Tag_BTI_extension, 2, // Allowed
+    Tag_nodefaults, 0,
+    Tag_also_compatible_with, Tag_CPU_arch, 22, 0, // Arch(CpuArch::V9A)
+    Tag_also_compatible_with, Tag_CPU_name, b'A', b'R', b'M', b'7', b'T', b'D', b'M', b'I', 0, // Reserved(Tag::CpuName("ARM7TDMI"))
+    Tag_conformance, b'2', b'0', b'2', b'3', b'Q', b'3', 0, // Conform("2023Q3")
+    Tag_T2EE_use, 0, // None
+    Tag_Virtualization_use, 2, // VExts
+    Tag_FramePointer_use, 1, // WithRecords
+    Tag_BTI_use, 1, // Enabled
+    Tag_PACRET_use, 1, // Enabled
+];
+
 #[test]
 fn test_tags() {
-    #[rustfmt::skip]
-    let raw = [
-        b'A', // version
-        0x9f, 0x00, 0x00, 0x00, // size
-        b'a', b'e', b'a', b'b', b'i', 0, // "aeabi" subsection
-        Tag_File, // file scope
-        0x95, 0x00, 0x00, 0x00, // scope size
-        Tag_CPU_raw_name, b'V', b'5', b'T', b'E', 0, // "V5TE"
-        Tag_CPU_name, b'A', b'R', b'M', b'9', b'4', b'6', b'E', b'-', b'S', 0, // ARM946E-S
-        Tag_CPU_arch, 4, // V5TE
-        Tag_CPU_arch_profile, 0, // NotApplicable
-        Tag_ARM_ISA_use, 1, // Allowed
-        Tag_THUMB_ISA_use, 1, // Allowed16Bit
-        Tag_FP_arch, 1, // V1
-        Tag_WMMX_arch, 2, // V2
-        Tag_Advanced_SIMD_arch, 4, // V8_1A
-        Tag_PCS_config, 3, // LinuxDso
-        Tag_ABI_PCS_R9_use, 1, // Sb
-        Tag_ABI_PCS_RW_data, 2, // SbRel
-        Tag_ABI_PCS_RO_data, 2, // None
-        Tag_ABI_PCS_GOT_use, 1, // Direct
-        Tag_ABI_PCS_wchar_t, 4, // Size4
-        Tag_ABI_FP_rounding, 0, // Nearest
-        Tag_ABI_FP_denormal, 2, // PreserveSign
-        Tag_ABI_FP_exceptions, 1, // CheckInexact
-        Tag_ABI_FP_user_exceptions, 1, // Enabled
-        Tag_ABI_FP_number_model, 2, // InfNaN
-        Tag_ABI_align_needed, 2, // Align4
-        Tag_ABI_align_needed, 4, // Align2n(4)
-        Tag_ABI_align_needed, 12, // Align2n(12)
-        Tag_ABI_align_preserved, 1, // Align8
-        Tag_ABI_align_preserved, 4, // Align2n(4)
-        Tag_ABI_align_preserved, 12, // Align2n(12)
-        Tag_ABI_enum_size, 1, // SmallestSize
-        Tag_ABI_HardFP_use, 0, // Implied
-        Tag_ABI_VFP_args, 2, // Toolchain
-        Tag_ABI_WMMX_args, 0, // Base
-        Tag_ABI_optimization_goals, 2, // OptimizeSpeed
-        Tag_ABI_FP_optimization_goals, 5, // FavorAccuracy
-        Tag_compatibility, 0, // Always
-        Tag_compatibility, 1, b'X', b'Y', b'Z', 0, // ByToolchain("XYZ")
-        Tag_compatibility, 42, b'f', b'o', b'o', 0, // Private { flag: 42, vendor: "foo" }
-        Tag_CPU_unaligned_access, 1, // Allowed
-        Tag_FP_HP_extension, 0, // IfExists
-        Tag_ABI_FP_16bit_format, 2, // Alternative
-        Tag_MPextension_use, 1, // Allowed
-        Tag_DIV_use, 1, // None
-        Tag_DSP_extension, 0, // IfExists
-        Tag_MVE_arch, 2, // IntFloat
-        Tag_PAC_extension, 1, // OnlyNopSpace
-        Tag_BTI_extension, 2, // Allowed
-        Tag_nodefaults, 0,
-        Tag_also_compatible_with, Tag_CPU_arch, 22, 0, // Arch(CpuArch::V9A)
-        Tag_also_compatible_with, Tag_CPU_name, b'A', b'R', b'M', b'7', b'T', b'D', b'M', b'I', 0, // Reserved(Tag::CpuName("ARM7TDMI"))
-        Tag_conformance, b'2', b'0', b'2', b'3', b'Q', b'3', 0, // Conform("2023Q3")
-        Tag_T2EE_use, 0, // None
-        Tag_Virtualization_use, 2, // VExts
-        Tag_FramePointer_use, 1, // WithRecords
-        Tag_BTI_use, 1, // Enabled
-        Tag_PACRET_use, 1, // Enabled
-    ];
-
-    let build_attrs = BuildAttrs::new(&raw, Endian::Little).unwrap();
+    let build_attrs = BuildAttrs::new(RAW, Endian::Little).unwrap();
     let mut subsections = build_attrs.subsections().map(|s| s.unwrap());
     let subsection = subsections.next().unwrap();
     assert!(subsection.is_aeabi());
@@ -137,3 +144,70 @@ fn test_tags() {
     assert_tag!(attributes, (145, Tag::BtiUse(BtiUse::Enabled)));
     assert_tag!(attributes, (147, Tag::PacretUse(PacretUse::Enabled)));
 }
+
+#[test]
+fn test_write_tags() {
+    let build_attrs = BuildAttrs::new(RAW, Endian::Little).unwrap();
+    let subsection = build_attrs.subsections().next().unwrap().unwrap();
+    let tags = subsection
+        .into_public_tag_iter()
+        .unwrap()
+        .map(|(_, tag)| tag)
+        .collect::<Vec<_>>();
+
+    let mut writer = BuildAttrsWriter::new(Endian::Little);
+    writer.add_public_tags(&tags);
+    assert_eq!(writer.finish(), RAW);
+}
+
+#[test]
+fn test_tag_names() {
+    assert_eq!(tag_name(Tag_ABI_VFP_args.into()), Some("Tag_ABI_VFP_args"));
+    assert_eq!(tag_name(Tag_VFP_arch.into()), Some("Tag_FP_arch"));
+    assert_eq!(tag_name(33), None);
+
+    let tag = Tag::AbiVfpArgs(AbiVfpArgs::Vfp);
+    assert_eq!(tag.name(), Some("Tag_ABI_VFP_args"));
+    assert_eq!(tag.to_string(), "VFP variant");
+    assert_eq!(Tag::CpuRawName("V5TE").to_string(), "\"V5TE\"");
+}
+
+#[test]
+fn test_tag_values() {
+    assert_eq!(tag_from_name("Tag_ABI_VFP_args"), Some(Tag_ABI_VFP_args.into()));
+    assert_eq!(tag_from_name("Tag_Foo"), None);
+    assert_eq!(tag_from_name("Tag_VFP_arch"), Some(Tag_FP_arch.into()));
+    assert_eq!(tag_from_name("Tag_MPextension_use_OLD"), Some(Tag_MPextension_use_OLD.into()));
+
+    assert_eq!(
+        Tag::from_uleb128(Tag_ABI_VFP_args.into(), 1),
+        Some(Tag::AbiVfpArgs(AbiVfpArgs::Vfp))
+    );
+    assert_eq!(Tag::from_uleb128(Tag_nodefaults.into(), 0), Some(Tag::NoDefaults));
+    assert_eq!(Tag::from_uleb128(Tag_CPU_name.into(), 1), None);
+    assert_eq!(Tag::from_uleb128(Tag_File.into(), 1), None);
+    assert_eq!(Tag::from_uleb128(33, 1), None);
+    assert_eq!(
+        Tag::from_uleb128(100, 1),
+        Some(Tag::Unknown {
+            tag: 100,
+            value: UnknownValue::Uleb128(1)
+        })
+    );
+    assert_eq!(Tag::from_string(Tag_CPU_raw_name.into(), "foo"), Some(Tag::CpuRawName("foo")));
+    assert_eq!(Tag::from_string(Tag_CPU_arch.into(), "foo"), None);
+
+    assert_eq!(Tag::AbiVfpArgs(AbiVfpArgs::Vfp).uleb128_value(), Some(1));
+    assert_eq!(Tag::NoDefaults.uleb128_value(), Some(0));
+    assert_eq!(Tag::CpuRawName("foo").uleb128_value(), None);
+    assert_eq!(Tag::CpuRawName("foo").string_value(), Some("foo"));
+    assert_eq!(Tag::AbiVfpArgs(AbiVfpArgs::Vfp).string_value(), None);
+
+    let mut attributes = Attributes::default();
+    attributes.set(Tag::AbiVfpArgs(AbiVfpArgs::Vfp));
+    attributes.set(Tag::AbiVfpArgs(AbiVfpArgs::Base));
+    attributes.set(Tag::NoDefaults);
+    assert_eq!(attributes.abi_vfp_args, Some(AbiVfpArgs::Base));
+    assert!(attributes.no_defaults);
+    assert_eq!(attributes.tags().len(), 2);
+}
@@ -0,0 +1,111 @@
+use arm_attr::{enums::*, error::*, globals::*, read::Endian, tag::Tag, BuildAttrs};
+
+#[rustfmt::skip]
+const RAW: &[u8] = &[
+    b'A', // version
+    0x25, 0x00, 0x00, 0x00, // size
+    b'a', b'e', b'a', b'b', b'i', 0, // "aeabi" subsection
+    Tag_File, 0x07, 0x00, 0x00, 0x00, // file scope
+        Tag_CPU_arch, 10, // V7
+    Tag_Section, 0x0b, 0x00, 0x00, 0x00, 1, 0, // section scope
+        Tag_nodefaults, 1, // not null
+        Tag_ABI_VFP_args, 1, // VFP
+    Tag_Section, 0x09, 0x00, 0x00, 0x00, 2, 0, // section scope
+        Tag_FP_arch, 2, // V2
+];
+
+#[test]
+fn test_try_public_tag_iter() {
+    let build_attrs = BuildAttrs::new(RAW, Endian::Little).unwrap();
+    let subsection = build_attrs.subsections().next().unwrap().unwrap();
+    let mut tags = subsection.into_try_public_tag_iter().unwrap();
+    assert_eq!(tags.next().unwrap().unwrap(), (0, Tag::File { end_offset: 7 }));
+    assert_eq!(tags.next().unwrap().unwrap(), (5, Tag::CpuArch(CpuArch::V7)));
+    assert_eq!(
+        tags.next().unwrap().unwrap(),
+        (
+            7,
+            Tag::Section {
+                end_offset: 18,
+                sections: vec![1]
+            }
+        )
+    );
+    let error = tags.next().unwrap().unwrap_err();
+    // Offsets in errors are from the start of the section, where the subsection data starts at 11
+    assert_eq!(error.start, 25);
+    assert_eq!(
+        error.location,
+        Location {
+            subsection: 0,
+            vendor: Some("aeabi".to_string()),
+            offset: 26,
+            tag: Some(Tag_nodefaults.into()),
+            scope: Some(ErrorScope::Section(vec![1])),
+        }
+    );
+    assert!(matches!(error.error, TagError::ExpectedNull));
+    assert!(tags.next().is_none());
+}
+
+#[test]
+fn test_try_public_tag_iter_recovery() {
+    let build_attrs = BuildAttrs::new(RAW, Endian::Little).unwrap();
+    let subsection = build_attrs.subsections().next().unwrap().unwrap();
+    let tags = subsection.into_try_public_tag_iter().unwrap().with_recovery();
+    let tags = tags.map(|tag| tag.map_err(|error| error.start)).collect::<Vec<_>>();
+    assert_eq!(
+        tags,
+        vec![
+            Ok((0, Tag::File { end_offset: 7 })),
+            Ok((5, Tag::CpuArch(CpuArch::V7))),
+            Ok((
+                7,
+                Tag::Section {
+                    end_offset: 18,
+                    sections: vec![1]
+                }
+            )),
+            Err(25),
+            // Skipped to the end of the section scope
+            Ok((
+                18,
+                Tag::Section {
+                    end_offset: 27,
+                    sections: vec![2]
+                }
+            )),
+            Ok((25, Tag::FpArch(FpArch::V2))),
+        ]
+    );
+}
+
+#[test]
+fn test_try_public_tag_iter_no_scope() {
+    #[rustfmt::skip]
+    let raw = [
+        b'A', // version
+        0x0e, 0x00, 0x00, 0x00, // size
+        b'a', b'e', b'a', b'b', b'i', 0, // "aeabi" subsection
+        Tag_nodefaults, 1, // not null, outside of any scope
+        Tag_CPU_arch, 10, // V7
+    ];
+    let build_attrs = BuildAttrs::new(&raw, Endian::Little).unwrap();
+    let subsection = build_attrs.subsections().next().unwrap().unwrap();
+    let mut tags = subsection.into_try_public_tag_iter().unwrap().with_recovery();
+    assert_eq!(tags.next().unwrap().unwrap_err().start, 11);
+    assert!(tags.next().is_none());
+}
+
+#[test]
+fn test_try_public_tag_iter_error_message() {
+    let build_attrs = BuildAttrs::new(RAW, Endian::Little).unwrap();
+    let subsection = build_attrs.subsections().next().unwrap().unwrap();
+    let error = subsection.into_try_public_tag_iter().unwrap().find_map(Result::err).unwrap();
+    assert_eq!(
+        error.to_string(),
+        "failed to read tag at offset 0x1a in subsection 0 'aeabi', tag 64 (Tag_nodefaults), in section scope [1]"
+    );
+    let source = std::error::Error::source(&error).unwrap();
+    assert_eq!(source.to_string(), "expected null");
+}
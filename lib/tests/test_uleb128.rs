@@ -0,0 +1,74 @@
+use arm_attr::{enums::*, error::*, globals::*, read::Endian, tag::Tag, BuildAttrs};
+
+macro_rules! assert_tag {
+    ($attr:expr, $tag:expr) => {
+        assert_eq!($attr.next(), Some($tag))
+    };
+}
+
+#[test]
+fn test_uleb128() {
+    #[rustfmt::skip]
+    let raw = [
+        b'A', // version
+        0x24, 0x00, 0x00, 0x00, // size
+        b'a', b'e', b'a', b'b', b'i', 0, // "aeabi" subsection
+        Tag_File, // file scope
+        0x1a, 0x00, 0x00, 0x00, // scope size
+        Tag_CPU_arch, 0x80, 0x01, // Unknown(128)
+        Tag_ABI_PCS_wchar_t, 0x84, 0x80, 0x80, 0x00, // Size4, non-canonical encoding
+        Tag_compatibility, 0xac, 0x02, b'b', b'a', b'r', 0, // Private { flag: 300, vendor: "bar" }
+        Tag_CPU_arch_profile, 0xc1, 0x80, 0x04, // Unknown(0x10041)
+        Tag_ABI_enum_size, 2, // Always32
+    ];
+
+    let build_attrs = BuildAttrs::new(&raw, Endian::Little).unwrap();
+    let mut subsections = build_attrs.subsections().map(|s| s.unwrap());
+    let subsection = subsections.next().unwrap();
+
+    let mut attributes = subsection.into_public_tag_iter().unwrap();
+    assert_tag!(attributes, (0, Tag::File { end_offset: 26 }));
+    assert_tag!(attributes, (5, Tag::CpuArch(CpuArch::Unknown(128))));
+    assert_tag!(attributes, (8, Tag::AbiPcsWcharT(AbiPcsWcharT::Size4)));
+    assert_tag!(
+        attributes,
+        (
+            13,
+            Tag::Compat(Compat::Private {
+                flag: 300,
+                vendor: "bar"
+            })
+        )
+    );
+    assert_tag!(attributes, (20, Tag::CpuArchProfile(CpuArchProfile::Unknown(0x10041))));
+    assert_tag!(attributes, (24, Tag::AbiEnumSize(AbiEnumSize::Always32)));
+    assert_eq!(attributes.next(), None);
+}
+
+#[test]
+fn test_uleb128_overflow() {
+    #[rustfmt::skip]
+    let raw = [
+        b'A', // version
+        0x17, 0x00, 0x00, 0x00, // size
+        b'a', b'e', b'a', b'b', b'i', 0, // "aeabi" subsection
+        Tag_File, // file scope
+        0x0d, 0x00, 0x00, 0x00, // scope size
+        Tag_CPU_arch, 0xff, 0xff, 0xff, 0xff, 0x10, // 0x10fffffff, too large for u32
+        Tag_ABI_enum_size, 2, // Always32
+    ];
+
+    let build_attrs = BuildAttrs::new(&raw, Endian::Little).unwrap();
+    let mut subsections = build_attrs.subsections().map(|s| s.unwrap());
+    let subsection = subsections.next().unwrap();
+
+    let result = subsection.into_public_attributes();
+    let Err(PublicAttrsError::Tag { location, error }) = result else {
+        panic!("expected a tag error, got {result:?}");
+    };
+    assert!(matches!(error, TagError::Read(ReadError::Overflow)));
+    // The start of the overflowing value, in the file scope
+    assert_eq!(location.offset, 17);
+    assert_eq!(location.tag, Some(Tag_CPU_arch.into()));
+    assert_eq!(location.scope, Some(ErrorScope::File));
+}
@@ -0,0 +1,115 @@
+use arm_attr::{
+    enums::*,
+    error::*,
+    globals::*,
+    read::Endian,
+    tag::{Tag, UnknownValue},
+    BuildAttrs,
+};
+
+macro_rules! assert_tag {
+    ($attr:expr, $tag:expr) => {
+        assert_eq!($attr.next(), Some($tag))
+    };
+}
+
+#[test]
+fn test_unknown() {
+    #[rustfmt::skip]
+    let raw = [
+        b'A', // version
+        0x20, 0x00, 0x00, 0x00, // size
+        b'a', b'e', b'a', b'b', b'i', 0, // "aeabi" subsection
+        Tag_File, // file scope
+        0x16, 0x00, 0x00, 0x00, // scope size
+        80, 0x81, 0x01, // even tag, ULEB128 value 129
+        81, b'h', b'i', 0, // odd tag, string value "hi"
+        Tag_CPU_arch, 4, // V5TE
+        0x80, 0x02, 7, // tag 256, ULEB128 value 7
+        0x81, 0x02, 0, // tag 257, empty string
+        Tag_ABI_enum_size, 2, // Always32
+    ];
+
+    let build_attrs = BuildAttrs::new(&raw, Endian::Little).unwrap();
+    let mut subsections = build_attrs.subsections().map(|s| s.unwrap());
+    let subsection = subsections.next().unwrap();
+
+    let mut attributes = subsection.into_public_tag_iter().unwrap();
+    assert_tag!(attributes, (0, Tag::File { end_offset: 22 }));
+    assert_tag!(
+        attributes,
+        (
+            5,
+            Tag::Unknown {
+                tag: 80,
+                value: UnknownValue::Uleb128(129)
+            }
+        )
+    );
+    assert_tag!(
+        attributes,
+        (
+            8,
+            Tag::Unknown {
+                tag: 81,
+                value: UnknownValue::String("hi")
+            }
+        )
+    );
+    assert_tag!(attributes, (12, Tag::CpuArch(CpuArch::V5TE)));
+    assert_tag!(
+        attributes,
+        (
+            14,
+            Tag::Unknown {
+                tag: 256,
+                value: UnknownValue::Uleb128(7)
+            }
+        )
+    );
+    assert_tag!(
+        attributes,
+        (
+            17,
+            Tag::Unknown {
+                tag: 257,
+                value: UnknownValue::String("")
+            }
+        )
+    );
+    assert_tag!(attributes, (20, Tag::AbiEnumSize(AbiEnumSize::Always32)));
+    assert_eq!(attributes.next(), None);
+
+    let subsection = build_attrs.subsections().next().unwrap().unwrap();
+    let file = subsection.into_public_attributes().unwrap();
+    assert_eq!(file.attributes.cpu_arch, Some(CpuArch::V5TE));
+    assert_eq!(file.attributes.abi_enum_size, Some(AbiEnumSize::Always32));
+    assert_eq!(file.attributes.unknown.get(&80), Some(&UnknownValue::Uleb128(129)));
+    assert_eq!(file.attributes.unknown.get(&81), Some(&UnknownValue::String("hi")));
+    assert_eq!(file.attributes.unknown.get(&256), Some(&UnknownValue::Uleb128(7)));
+    assert_eq!(file.attributes.unknown.get(&257), Some(&UnknownValue::String("")));
+}
+
+#[test]
+fn test_unknown_below_32() {
+    #[rustfmt::skip]
+    let raw = [
+        b'A', // version
+        0x11, 0x00, 0x00, 0x00, // size
+        b'a', b'e', b'a', b'b', b'i', 0, // "aeabi" subsection
+        Tag_File, // file scope
+        0x07, 0x00, 0x00, 0x00, // scope size
+        0, 0, // tag 0 is not known and cannot be skipped
+    ];
+
+    let build_attrs = BuildAttrs::new(&raw, Endian::Little).unwrap();
+    let subsection = build_attrs.subsections().next().unwrap().unwrap();
+    let result = subsection.into_public_attributes();
+    assert!(matches!(
+        result,
+        Err(PublicAttrsError::Tag {
+            error: TagError::IncompatibleTagValue(0),
+            ..
+        })
+    ));
+}
@@ -0,0 +1,77 @@
+use arm_attr::{
+    globals::*,
+    read::Endian,
+    vendor::{default_parser, ValueType, VendorParser, VendorTag, VendorValue},
+    BuildAttrs,
+};
+
+#[rustfmt::skip]
+const RAW: &[u8] = &[
+    b'A', // version
+    0x18, 0x00, 0x00, 0x00, // size
+    b'g', b'n', b'u', 0, // "gnu" subsection
+    Tag_File, 0x10, 0x00, 0x00, 0x00, // whole file
+        Tag_compatibility, 1, b'f', b'o', b'o', 0, // flag 1, "foo"
+        4, 2, // even tag, ULEB128 value 2
+        5, b'x', 0, // odd tag, string value "x"
+    0x15, 0x00, 0x00, 0x00, // size
+    b'a', b'c', b'm', b'e', 0, // "acme" subsection
+    Tag_File, 0x0c, 0x00, 0x00, 0x00, // whole file
+        4, b'h', b'i', 0, // string value "hi"
+        6, 0x81, 0x01, // ULEB128 value 129
+];
+
+macro_rules! assert_tag {
+    ($attr:expr, $offset:expr, $tag:expr, $value:expr) => {
+        assert_eq!(
+            $attr.next(),
+            Some((
+                $offset,
+                VendorTag::Attribute {
+                    tag: $tag,
+                    value: $value
+                }
+            ))
+        )
+    };
+}
+
+#[test]
+fn test_gnu() {
+    let build_attrs = BuildAttrs::new(RAW, Endian::Little).unwrap();
+    let subsection = build_attrs.subsections().next().unwrap().unwrap();
+    assert_eq!(subsection.vendor_name(), "gnu");
+
+    let parser = default_parser(subsection.vendor_name());
+    assert_eq!(parser.tag_name(32), Some("Tag_compatibility"));
+    let mut tags = subsection.into_vendor_tag_iter(parser);
+    assert_eq!(tags.next(), Some((0, VendorTag::File { end_offset: 16 })));
+    assert_tag!(tags, 5, 32, VendorValue::Uleb128String(1, "foo"));
+    assert_tag!(tags, 11, 4, VendorValue::Uleb128(2));
+    assert_tag!(tags, 13, 5, VendorValue::String("x"));
+    assert_eq!(tags.next(), None);
+}
+
+struct AcmeParser;
+
+impl VendorParser for AcmeParser {
+    fn value_type(&self, tag: u32) -> ValueType {
+        match tag {
+            4 => ValueType::String,
+            _ => ValueType::from_parity(tag),
+        }
+    }
+}
+
+#[test]
+fn test_custom_parser() {
+    let build_attrs = BuildAttrs::new(RAW, Endian::Little).unwrap();
+    let subsection = build_attrs.subsections().nth(1).unwrap().unwrap();
+    assert_eq!(subsection.vendor_name(), "acme");
+
+    let mut tags = subsection.into_vendor_tag_iter(&AcmeParser);
+    assert_eq!(tags.next(), Some((0, VendorTag::File { end_offset: 12 })));
+    assert_tag!(tags, 5, 4, VendorValue::String("hi"));
+    assert_tag!(tags, 9, 6, VendorValue::Uleb128(129));
+    assert_eq!(tags.next(), None);
+}